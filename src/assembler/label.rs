@@ -10,20 +10,14 @@ use std::collections::HashMap;
 pub struct Label {
     pub name: String,
     pub at: usize,
-    pub global: bool
-}
-
-struct UnnamedLabel {
-    at: Option<usize>,
-    global: bool,
 }
 
 pub struct LabelMap {
-    map: HashMap<String, UnnamedLabel>,
+    map: HashMap<String, usize>,
 }
 
 pub struct Iter<'a> {
-    inner: HMIter<'a, String, UnnamedLabel>
+    inner: HMIter<'a, String, usize>
 }
 
 impl LabelMap {
@@ -32,15 +26,11 @@ impl LabelMap {
     }
 
     pub fn insert_label(&mut self, name: String, at: usize) {
-        self.map.insert(name, UnnamedLabel { at: Some(at), global: false });
+        self.map.insert(name, at);
     }
 
-    pub fn make_global(&mut self, name: String) {
-        if self.map.contains_key(&*name) {
-            self.map.get_mut(name.as_str()).unwrap().global = true;
-        } else {
-            self.map.insert(name, UnnamedLabel { at: None, global: true });
-        }
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.map.get(name).copied()
     }
 
     pub fn iter(&self) -> Iter {
@@ -54,21 +44,6 @@ impl<'a> Iterator for Iter<'a> {
     type Item = Label;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let i = self.inner.next();
-
-        if let Some(i) = i {
-            if i.1.at.is_some() {
-                Some(Label {
-                    name: i.0.clone(),
-                    at: i.1.at.unwrap(),
-                    global: i.1.global
-                })
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+        self.inner.next().map(|(name, at)| Label { name: name.clone(), at: *at })
     }
 }
-
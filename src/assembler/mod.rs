@@ -6,24 +6,36 @@
 
 mod label;
 mod section;
+mod tests;
 
 use crate::assembler::section::Section;
-use crate::parse::helpers::Relativity;
-use crate::parse::{Directive, Line};
-use object::write::{Mangling, Relocation, StandardSection, Symbol, SymbolSection};
-use object::{
-    write, Architecture, BinaryFormat, Endianness, RelocationEncoding, RelocationKind, SectionKind, SymbolFlags,
-    SymbolKind, SymbolScope,
-};
-use std::collections::HashMap;
+use crate::instructions::Subtrahend;
+use crate::parse::{Alignment, Directive, Line, SectionAttributes, SymbolSize, SymbolType, SymbolValue};
+use crate::target::Target;
+use object::write::{Mangling, Relocation, StandardSection, Symbol, SymbolId, SymbolSection};
+use object::{elf, write, BinaryFormat, Endianness, SectionFlags, SectionKind, SymbolFlags, SymbolKind, SymbolScope};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::error::Error;
 use std::fs::File;
+use std::io::Write;
 use std::path::Path;
 
 pub struct Module {
     sections: HashMap<String, Section>,
+    features: BTreeSet<&'static str>,
+    /// Symbols made global with `.globl`, which can come before their section
+    globals: HashSet<String>,
+    types: HashMap<String, SymbolType>,
+    sizes: HashMap<String, SymbolSize>,
+    /// Symbols defined with `.set` or `.equ` as a constant, which are absolute in the object file
+    constants: HashMap<String, i64>,
+    /// Symbols defined with `.set` as another symbol, like `.set alias, main`
+    aliases: HashMap<String, String>,
+    /// Symbols reserved with `.comm`, with their size and alignment in bytes
+    commons: HashMap<String, (u64, u64)>,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ObjectFileType {
     Elf,
     MachO,
@@ -39,146 +51,613 @@ impl Default for ObjectFileType {
     }
 }
 
+/// Finds a symbol, or adds it as an undefined one, which the linker has to find in another module. `object` only
+/// looks up functions and variables, so `others` remembers the other symbols added before
+fn symbol_or_undefined(object: &mut write::Object, others: &mut HashMap<String, SymbolId>, name: &str) -> SymbolId {
+    if let Some(id) = object.symbol_id(name.as_bytes()).or_else(|| others.get(name).copied()) {
+        return id;
+    }
+
+    let symbol = Symbol {
+        name: name.as_bytes().to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Unknown,
+        scope: SymbolScope::Unknown,
+        weak: false,
+        section: SymbolSection::Undefined,
+        flags: SymbolFlags::None,
+    };
+    let id = object.add_symbol(symbol);
+    others.insert(name.to_string(), id);
+    id
+}
+
+/// The symbol an alias stands for in the end, which can be an alias itself
+fn resolve<'a>(aliases: &'a HashMap<String, String>, mut name: &'a str) -> &'a str {
+    // The count stops at aliases that form a loop, which are rejected before they're used
+    for _ in 0..=aliases.len() {
+        match aliases.get(name) {
+            Some(to) => name = to,
+            None => break,
+        }
+    }
+    name
+}
+
+/// Whether a label is only for the assembler, like GCC's `.LC0`, the `.L.here.N` made for `.` or NASM's `..@N.name`
+/// from macros. ELF relocations take these from their section, so they aren't written as symbols
+fn is_assembler_local(name: &str, format: ObjectFileType) -> bool {
+    format == ObjectFileType::Elf && (name.starts_with(".L") || name.starts_with("..@"))
+}
+
+/// Whether a section holds instructions, so its labels are functions and it's padded with NOPs
+fn is_code(name: &str, section: &Section) -> bool {
+    let executable = section.attributes.is_some_and(|a| a.flags & elf::SHF_EXECINSTR as u64 != 0);
+    matches!(name, "text" | "__TEXT,__text") || name.starts_with(".text") || executable
+}
+
+/// The flags and type GNU as gives an ELF section by its name, when `.section` doesn't give them
+fn default_attributes(name: &str) -> SectionAttributes {
+    let prefixes = [
+        (".text", elf::SHF_ALLOC | elf::SHF_EXECINSTR, elf::SHT_PROGBITS),
+        (".rodata.str", elf::SHF_ALLOC | elf::SHF_MERGE | elf::SHF_STRINGS, elf::SHT_PROGBITS),
+        (".rodata", elf::SHF_ALLOC, elf::SHT_PROGBITS),
+        (".data", elf::SHF_ALLOC | elf::SHF_WRITE, elf::SHT_PROGBITS),
+        (".bss", elf::SHF_ALLOC | elf::SHF_WRITE, elf::SHT_NOBITS),
+        (".tdata", elf::SHF_ALLOC | elf::SHF_WRITE | elf::SHF_TLS, elf::SHT_PROGBITS),
+        (".tbss", elf::SHF_ALLOC | elf::SHF_WRITE | elf::SHF_TLS, elf::SHT_NOBITS),
+        (".init_array", elf::SHF_ALLOC | elf::SHF_WRITE, elf::SHT_INIT_ARRAY),
+        (".fini_array", elf::SHF_ALLOC | elf::SHF_WRITE, elf::SHT_FINI_ARRAY),
+        (".preinit_array", elf::SHF_ALLOC | elf::SHF_WRITE, elf::SHT_PREINIT_ARRAY),
+    ];
+    let (flags, typ) = prefixes
+        .into_iter()
+        .find(|(prefix, ..)| name.starts_with(prefix))
+        .map_or((0, elf::SHT_PROGBITS), |(_, flags, typ)| (flags, typ));
+
+    // Strings are merged byte by byte
+    let entsize = if flags & elf::SHF_MERGE != 0 { 1 } else { 0 };
+    SectionAttributes { flags: flags as u64, typ: Some(typ), entsize }
+}
+
+/// The kind `object` writes an ELF section as, which gives it its type
+fn elf_kind(attributes: SectionAttributes) -> SectionKind {
+    let has = |flag: u32| attributes.flags & flag as u64 != 0;
+    match attributes.typ.unwrap_or(elf::SHT_PROGBITS) {
+        elf::SHT_NOBITS if has(elf::SHF_TLS) => SectionKind::UninitializedTls,
+        elf::SHT_NOBITS => SectionKind::UninitializedData,
+        elf::SHT_PROGBITS if has(elf::SHF_EXECINSTR) => SectionKind::Text,
+        elf::SHT_PROGBITS if has(elf::SHF_TLS) => SectionKind::Tls,
+        // `object` only writes merged sections with an entry size of one
+        elf::SHT_PROGBITS if has(elf::SHF_MERGE) && has(elf::SHF_STRINGS) && attributes.entsize == 1 => {
+            SectionKind::ReadOnlyString
+        }
+        elf::SHT_PROGBITS if has(elf::SHF_WRITE) => SectionKind::Data,
+        elf::SHT_PROGBITS if has(elf::SHF_ALLOC) => SectionKind::ReadOnlyData,
+        elf::SHT_PROGBITS => SectionKind::Other,
+        elf::SHT_NOTE => SectionKind::Note,
+        typ => SectionKind::Elf(typ),
+    }
+}
+
+/// Whether a section is uninitialized, so it only has a size in the object file
+fn is_nobits(name: &str) -> bool {
+    matches!(name, "bss" | "__DATA,__bss" | "__DATA,__thread_bss")
+        || name.starts_with(".bss")
+        || name.starts_with(".tbss")
+}
+
+/// The boundary of an alignment in bytes, which has to be a power of two
+fn alignment_bytes(alignment: Alignment, target: &dyn Target, format: ObjectFileType) -> Result<u64, String> {
+    alignment.bytes(target.align_in_bytes(format)).ok_or_else(|| match alignment {
+        Alignment::Bytes(n) | Alignment::PowerOfTwo(n) | Alignment::Either(n) => {
+            format!("Can't align to {}, it's not a power of two", n)
+        }
+    })
+}
+
+/// The alignment of `.comm`, which is in bytes in ELF and a power of two in Mach-O for every target
+fn common_alignment(alignment: Alignment, format: ObjectFileType) -> Alignment {
+    match alignment {
+        Alignment::Either(n) if format == ObjectFileType::Elf => Alignment::Bytes(n),
+        Alignment::Either(n) => Alignment::PowerOfTwo(n),
+        alignment => alignment,
+    }
+}
+
 impl Module {
-    pub fn from_lines(lines: Vec<Line>) -> Self {
+    pub fn from_lines(lines: Vec<Line>, target: &dyn Target, format: ObjectFileType) -> Result<Self, Box<dyn Error>> {
         let mut sections = HashMap::new();
-        sections.insert("__TEXT,__text".to_string(), Section::new());
+        sections.insert("__TEXT,__text".to_string(), Section::new(false));
 
-        let mut current_section = sections.get_mut("__TEXT,__text").unwrap();
+        let mut current = "__TEXT,__text".to_string();
+        let mut code = true;
+        let mut features = BTreeSet::new();
+        let mut globals = HashSet::new();
+        let mut types = HashMap::new();
+        let mut sizes = HashMap::new();
+        let mut constants = HashMap::new();
+        let mut aliases = HashMap::new();
+        let mut commons = HashMap::new();
+        // Common symbols made local are reserved like with `.lcomm`, `.local` usually comes first but doesn't have to
+        let local = lines
+            .iter()
+            .filter_map(|line| match line {
+                Line::Directive(Directive::Local(name)) => Some(name.clone()),
+                _ => None,
+            })
+            .collect::<HashSet<String>>();
+        // Labels made for `.` in instructions and data, counted so they're unique
+        let mut locations = 0;
+        let mut location = || {
+            locations += 1;
+            format!(".L.here.{}", locations)
+        };
 
         for line in lines {
+            let line = match line {
+                Line::Directive(Directive::Common(name, size, alignment)) if local.contains(&name) => {
+                    let symbol = Some((name, size, common_alignment(alignment, format)));
+                    Line::Directive(Directive::Zerofill { section: None, symbol })
+                }
+                line => line,
+            };
+            let current_section = sections.get_mut(&current).unwrap();
             match line {
                 Line::Directive(dir) => match dir {
-                    Directive::Asciz(string) => {
-                        current_section.write_string(string);
+                    Directive::Align(alignment, fill, max_skip) => {
+                        let align = alignment_bytes(alignment, target, format)?;
+                        // Code is padded with NOPs, unless the fill is something else than the one byte NOP
+                        let nops = code && fill.is_none_or(|fill| target.nops(1, None) == Some(vec![fill]));
+                        current_section.align(align, max_skip, |count| {
+                            if nops {
+                                target.nops(count, None).unwrap()
+                            } else {
+                                vec![fill.unwrap_or(0); count]
+                            }
+                        });
+                    }
+                    Directive::Bytes(bytes) => {
+                        current_section.write_bytes(&bytes);
+                    }
+                    Directive::Data(size, values) => {
+                        // `.` in data is the location of the value itself
+                        for value in values {
+                            let (from, at) = (current_section.references.len(), current_section.at());
+                            current_section.write_value(size, value);
+                            current_section.name_location(from, at, &mut location);
+                        }
                     }
                     Directive::Global(name) => {
-                        current_section.label_map.make_global(name);
+                        globals.insert(name);
+                    }
+                    Directive::Common(name, size, alignment) => {
+                        let align = alignment_bytes(common_alignment(alignment, format), target, format)?;
+                        commons.insert(name, (size as u64, align));
                     }
-                    Directive::Section(name) => {
-                        if !sections.contains_key(name.as_str()) {
-                            sections.insert(name.clone(), Section::new());
+                    Directive::Org(location, fill) => {
+                        let at = current_section.at();
+                        // The parser only lets through constants and locations relative to `.`
+                        let relative = if location.symbol.is_some() { at as i64 } else { 0 };
+                        let location = relative + location.addend;
+                        if location < at as i64 {
+                            return Err(format!("Can't move back from {:#x} to {:#x} with .org", at, location).into());
+                        }
+                        let location = location as usize;
+                        if fill == 0 {
+                            current_section.write_zeros(location - at);
+                        } else {
+                            current_section.write_bytes(&vec![fill; location - at]);
+                        }
+                    }
+                    Directive::Section(name, attributes) => {
+                        // Like in GNU as, the section keeps the attributes it was made with
+                        let section = sections.entry(name.clone()).or_insert_with(|| {
+                            let nobits = attributes.is_some_and(|a| a.typ == Some(elf::SHT_NOBITS));
+                            let mut section = Section::new(is_nobits(&name) || nobits);
+                            section.attributes = attributes;
+                            section
+                        });
+                        code = is_code(&name, section);
+                        current = name;
+                    }
+                    Directive::Set(name, SymbolValue::Constant(value)) => {
+                        aliases.remove(&name);
+                        constants.insert(name, value);
+                    }
+                    Directive::Set(name, SymbolValue::Symbol(to)) => {
+                        constants.remove(&name);
+                        aliases.insert(name, to);
+                    }
+                    Directive::Size(name, size) => {
+                        // GCC writes `.size main, .-main`, where . is the current location
+                        let size = match size {
+                            SymbolSize::Difference(end, start) if end == "." => {
+                                match current_section.label_map.get(&start) {
+                                    Some(start) => SymbolSize::Constant((current_section.at() - start) as u64),
+                                    None => SymbolSize::Difference(end, start),
+                                }
+                            }
+                            size => size,
+                        };
+                        sizes.insert(name, size);
+                    }
+                    Directive::Space(count) => {
+                        current_section.write_zeros(count);
+                    }
+                    Directive::Type(name, typ) => {
+                        types.insert(name, typ);
+                    }
+                    Directive::Zerofill { section, symbol } => {
+                        let name = section.unwrap_or_else(|| match format {
+                            ObjectFileType::Elf => ".bss".to_string(),
+                            ObjectFileType::MachO => "__DATA,__bss".to_string(),
+                        });
+                        // The space is reserved without switching to the section
+                        let section = sections.entry(name).or_insert_with(|| Section::new(true));
+
+                        if let Some((symbol, size, alignment)) = symbol {
+                            let align = alignment_bytes(alignment, target, format)?;
+                            section.align(align, None, |count| vec![0; count]);
+                            section.label_map.insert_label(symbol.clone(), section.at());
+                            section.write_zeros(size);
+
+                            types.insert(symbol.clone(), SymbolType::Object);
+                            sizes.insert(symbol, SymbolSize::Constant(size as u64));
                         }
-                        current_section = sections.get_mut(name.as_str()).unwrap()
                     }
                     _ => {}
                 },
                 Line::Label(label) => current_section.label_map.insert_label(label, current_section.at()),
                 Line::Instruction(instr) => {
-                    current_section.write_instruction(&instr);
+                    features.extend(instr.feature());
+                    // `.` in an instruction is where it starts
+                    let (from, at) = (current_section.references.len(), current_section.at());
+                    current_section.write_instruction(&*instr)?;
+                    current_section.name_location(from, at, &mut location);
+                }
+            }
+        }
+
+        // Aliases that lead back to themselves never reach a symbol
+        if let Some(alias) = aliases.keys().find(|alias| aliases.contains_key(resolve(&aliases, alias))) {
+            return Err(format!("Symbol definition loop encountered at '{}'", alias).into());
+        }
+
+        // Aliases of constants are constants, which can be defined after the alias
+        let constant_aliases = aliases
+            .keys()
+            .filter_map(|alias| Some((alias.clone(), *constants.get(resolve(&aliases, alias))?)))
+            .collect::<Vec<(String, i64)>>();
+        for (alias, value) in constant_aliases {
+            aliases.remove(&alias);
+            constants.insert(alias, value);
+        }
+
+        // Differences of labels in the same section are known now, only the others need relocations
+        let mut labels = HashMap::new();
+        for (name, section) in &sections {
+            labels.extend(section.label_map.iter().map(|label| (label.name, (name.clone(), label.at))));
+        }
+        for alias in aliases.keys() {
+            if let Some(label) = labels.get(resolve(&aliases, alias)).cloned() {
+                labels.insert(alias.clone(), label);
+            }
+        }
+        for (name, section) in &mut sections {
+            section.fold_differences(name, &labels)?;
+            section.fold_constants(&constants)?;
+
+            // ELF relocates the others relative to the value, so what's subtracted has to be in the same section
+            if format == ObjectFileType::Elf {
+                for r in &section.references {
+                    match &r.minus {
+                        Some(Subtrahend::Symbol(minus)) if labels.get(minus).map(|l| &l.0) != Some(name) => {
+                            let error = format!("Can't subtract '{}' from '{}', it's in another section", minus, r.to);
+                            return Err(error.into());
+                        }
+                        _ => {}
+                    }
                 }
             }
         }
 
-        Module { sections }
+        Ok(Module { sections, features, globals, types, sizes, constants, aliases, commons })
+    }
+
+    /// The CPU features used by the modules instructions
+    pub fn features(&self) -> impl Iterator<Item = &str> {
+        self.features.iter().copied()
     }
 
-    pub fn write_to_file<P: AsRef<Path>>(self, name: P, object_type: ObjectFileType) -> Result<(), Box<dyn Error>> {
-        let file = File::options().create(true).write(true).open(name.as_ref())?;
+    pub fn write_to_file<P: AsRef<Path>>(
+        self,
+        name: P,
+        object_type: ObjectFileType,
+        target: &dyn Target,
+    ) -> Result<(), Box<dyn Error>> {
+        let bytes = self.into_object_file(object_type, target)?;
+        let mut file = File::options().create(true).write(true).truncate(true).open(name.as_ref())?;
+        file.write_all(&bytes)?;
 
+        Ok(())
+    }
+
+    /// The object file of the module
+    pub fn into_object_file(self, object_type: ObjectFileType, target: &dyn Target) -> Result<Vec<u8>, Box<dyn Error>> {
         let binary_format = match object_type {
             ObjectFileType::Elf => BinaryFormat::Elf,
             ObjectFileType::MachO => BinaryFormat::MachO,
         };
 
-        let mut object = write::Object::new(binary_format, Architecture::X86_64, Endianness::Little);
+        let mut object = write::Object::new(binary_format, target.architecture(), Endianness::Little);
 
         object.mangling = Mangling::None;
 
         let mut relocations = Vec::new();
+        // Where each label ended up, for differences relocated relative to the value
+        let mut positions = HashMap::new();
+        // Kinds of the labels not written as symbols, for aliases of them
+        let mut locals = HashMap::new();
+        // Symbols written for some of those after all, because they're in a section the linker merges
+        let mut kept = HashMap::new();
+        let mut merged = HashSet::new();
+        let mut thread_local = HashSet::new();
 
         for (name, sec) in self.sections {
-            let mut code = false;
+            let code = is_code(&name, &sec);
+            // ELF sections take the type from their name if `.section` only gives the flags, like `.init_array,"aw"`
+            let attributes = (object_type == ObjectFileType::Elf).then(|| {
+                let defaults = default_attributes(&name);
+                match sec.attributes {
+                    Some(given) => SectionAttributes { typ: given.typ.or(defaults.typ), ..given },
+                    None => defaults,
+                }
+            });
 
             let section = match &*name {
-                "text" | "__TEXT,__text" => {
-                    code = true;
-                    object.section_id(StandardSection::Text)
-                }
-                "data" | "__DATA,__data" => object.section_id(StandardSection::Data),
-                "rodata" | "__TEXT,__const" | "__DATA,__const" | "__TEXT,__literal4" => object.section_id(StandardSection::ReadOnlyData),
+                "text" | ".text" | "__TEXT,__text" => object.section_id(StandardSection::Text),
+                "data" | ".data" | "__DATA,__data" => object.section_id(StandardSection::Data),
+                "rodata" | ".rodata" | "__TEXT,__const" | "__DATA,__const" | "__TEXT,__literal4" => object.section_id(StandardSection::ReadOnlyData),
                 "rodata.str" | "__TEXT,__cstring" => object.section_id(StandardSection::ReadOnlyString),
-                "bss" | "__DATA,__bss" => object.section_id(StandardSection::UninitializedData),
+                "bss" | ".bss" | "__DATA,__bss" => object.section_id(StandardSection::UninitializedData),
+                ".tbss" | "__DATA,__thread_bss" => object.section_id(StandardSection::UninitializedTls),
                 // Todo: do the other standard sections
+                _ if name.starts_with('.') => {
+                    // Other ELF sections, like .text.startup, .tdata or .rodata.str1.1, get their kind from their type
+                    // and flags
+                    let attributes = attributes.unwrap_or_else(|| default_attributes(&name));
+                    let kind = elf_kind(attributes);
+                    let section = object.add_section(vec![], name.as_bytes().to_vec(), kind);
+
+                    // Merged sections need the entry size that comes with the kind
+                    let mut sh_flags = attributes.flags;
+                    if kind == SectionKind::ReadOnlyString {
+                        merged.insert(section);
+                    } else {
+                        sh_flags &= !(elf::SHF_MERGE | elf::SHF_STRINGS) as u64;
+                    }
+                    if object_type == ObjectFileType::Elf {
+                        object.section_mut(section).flags = SectionFlags::Elf { sh_flags };
+                    }
+                    section
+                }
                 _ => {
                     let mut s = name.split(',');
                     let segment = s.next().unwrap().as_bytes().to_vec();
                     let section = s.next().unwrap().as_bytes().to_vec();
 
-                    object.add_section(segment, section, SectionKind::Unknown)
+                    let kind = if sec.nobits { SectionKind::UninitializedData } else { SectionKind::Unknown };
+                    object.add_section(segment, section, kind)
                 }
             };
 
-            object.append_section_data(section, &*sec.bytes, 4 /*todo: read align from directives*/);
+            // Several names can map to the same section, e.g. text and .text. Empty ones mustn't pad it
+            let align = if sec.at() == 0 { 1 } else { sec.align };
+            // Uninitialized sections like .bss only have a size, which is what NASM's resb reserves
+            let base = if sec.nobits {
+                if sec.initialized {
+                    return Err(format!("Section '{}' can't hold initialized data", name).into());
+                }
+                object.append_section_bss(section, sec.at() as u64, align)
+            } else {
+                object.append_section_data(section, &*sec.bytes, align)
+            } as usize;
 
+            // Thread local variables are their own kind of symbol
+            let tls = name == "__DATA,__thread_bss" || attributes.is_some_and(|a| a.flags & elf::SHF_TLS as u64 != 0);
+            if tls {
+                thread_local.insert(section);
+            }
             for label in sec.label_map.iter() {
+                let kind = match self.types.get(&label.name) {
+                    _ if tls => SymbolKind::Tls,
+                    Some(SymbolType::Function) => SymbolKind::Text,
+                    Some(SymbolType::Object) => SymbolKind::Data,
+                    _ if code => SymbolKind::Text,
+                    _ => SymbolKind::Data,
+                };
+
+                let size = match self.sizes.get(&label.name) {
+                    Some(SymbolSize::Constant(size)) => *size,
+                    Some(SymbolSize::Difference(end, start)) => {
+                        let end_at = sec.label_map.get(end);
+                        let start_at = sec.label_map.get(start);
+                        if let (Some(end_at), Some(start_at)) = (end_at, start_at) {
+                            end_at.wrapping_sub(start_at) as u64
+                        } else {
+                            return Err(format!("Size of '{}' isn't the distance between two labels in its section", label.name).into());
+                        }
+                    }
+                    None => 0,
+                };
+
+                positions.insert(label.name.clone(), (section, base + label.at));
+                if is_assembler_local(&label.name, object_type) {
+                    locals.insert(label.name, kind);
+                    continue;
+                }
+
+                let global = self.globals.contains(&label.name);
                 let symbol = Symbol {
                     name: label.name.into_bytes(),
-                    value: label.at as u64,
-                    size: 0,
-                    kind: if code { SymbolKind::Text } else { SymbolKind::Data },
-                    scope: if label.global { SymbolScope::Dynamic } else { SymbolScope::Linkage },
+                    value: (base + label.at) as u64,
+                    size,
+                    kind,
+                    scope: if global { SymbolScope::Dynamic } else { SymbolScope::Compilation },
                     weak: false,
                     section: SymbolSection::Absolute,
                     flags: SymbolFlags::None,
                 };
 
                 let symbol_id = object.add_symbol(symbol);
-                object.set_symbol_data(symbol_id, section, label.at as u64, 0);
+                object.set_symbol_data(symbol_id, section, (base + label.at) as u64, size);
             }
 
-            for rel in sec.references {
+            for mut rel in sec.references {
+                rel.at += base;
                 relocations.push((section, rel));
             }
         }
 
-        for rel in relocations {
-            let to_op = object.symbol_id(rel.1.to.as_bytes());
-            let to = if let Some(to_op) = to_op {
-                to_op
-            } else {
+        // Constants have no type in ELF, Mach-O doesn't write the kind but only takes typed symbols
+        let constant_kind = if object_type == ObjectFileType::Elf { SymbolKind::Label } else { SymbolKind::Data };
+        let mut others = HashMap::new();
+        for (name, value) in self.constants {
+            let global = self.globals.contains(&name);
+            let symbol = Symbol {
+                name: name.clone().into_bytes(),
+                value: value as u64,
+                size: 0,
+                kind: constant_kind,
+                scope: if global { SymbolScope::Dynamic } else { SymbolScope::Compilation },
+                weak: false,
+                section: SymbolSection::Absolute,
+                flags: SymbolFlags::None,
+            };
+            others.insert(name, object.add_symbol(symbol));
+        }
+
+        // Common symbols are global, ELF gives their alignment as the value
+        for (name, (size, align)) in self.commons {
+            let symbol = Symbol {
+                name: name.into_bytes(),
+                value: align,
+                size,
+                kind: SymbolKind::Data,
+                scope: SymbolScope::Dynamic,
+                weak: false,
+                section: SymbolSection::Undefined,
+                flags: SymbolFlags::None,
+            };
+            object.add_common_symbol(symbol, size, align);
+        }
+
+        // Aliases of labels are symbols at the same place, others only stand for the symbol in relocations
+        for (alias, to) in &self.aliases {
+            let to = resolve(&self.aliases, to);
+            let kind = object.symbol_id(to.as_bytes()).map(|id| object.symbol(id).kind);
+            let kind = kind.or_else(|| locals.get(to).copied());
+            if let (Some((section, at)), Some(kind)) = (positions.get(to), kind) {
+                let global = self.globals.contains(alias);
                 let symbol = Symbol {
-                    name: rel.1.to.into_bytes(),
-                    value: 0,
+                    name: alias.clone().into_bytes(),
+                    value: *at as u64,
                     size: 0,
-                    kind: SymbolKind::Unknown,
-                    scope: SymbolScope::Unknown,
+                    kind,
+                    scope: if global { SymbolScope::Dynamic } else { SymbolScope::Compilation },
                     weak: false,
-                    section: SymbolSection::Undefined,
+                    section: SymbolSection::Absolute,
                     flags: SymbolFlags::None,
                 };
+                let symbol_id = object.add_symbol(symbol);
+                object.set_symbol_data(symbol_id, *section, *at as u64, 0);
+            }
+        }
 
-                object.add_symbol(symbol)
-            };
+        for mut rel in relocations {
+            rel.1.to = resolve(&self.aliases, &rel.1.to).to_string();
+            if let Some(Subtrahend::Symbol(minus)) = &mut rel.1.minus {
+                *minus = resolve(&self.aliases, minus).to_string();
+            }
 
-            let kind = match rel.1.rel {
-                Relativity::Absolute => RelocationKind::Absolute,
-                Relativity::Relative | Relativity::RipRelative => RelocationKind::Relative,
+            // The offsets TLS specifiers stand for only exist for variables in thread local sections. Mach-O's
+            // variables are descriptors in __thread_vars instead
+            if let (Some(specifier), Some((section, _))) = (rel.1.specifier, positions.get(&rel.1.to)) {
+                if specifier.is_tls() && object_type == ObjectFileType::Elf && !thread_local.contains(section) {
+                    return Err(format!("{:?} is used with '{}', which isn't thread local", specifier, rel.1.to).into());
+                }
+            }
+
+            // Assembler local labels are relocated from the start of their section
+            let (to, offset) = match positions.get(&rel.1.to) {
+                Some((section, at)) if locals.contains_key(&rel.1.to) => (object.section_symbol(*section), *at as i64),
+                _ => (symbol_or_undefined(&mut object, &mut others, &rel.1.to), 0),
             };
 
-            let encoding = match rel.1.rel {
-                Relativity::Absolute => RelocationEncoding::X86Signed,
-                Relativity::Relative => RelocationEncoding::X86Branch,
-                Relativity::RipRelative => RelocationEncoding::X86RipRelative,
+            // Labels not made global are the only symbols that can't be defined by another module
+            let preemptible = object.symbol(to).scope != SymbolScope::Compilation;
+            let mut typ = target.relocation(&rel.1, preemptible, object_type)?;
+
+            if let Some(Subtrahend::Symbol(minus)) = &rel.1.minus {
+                if let Some(kind) = typ.subtractor {
+                    let symbol = symbol_or_undefined(&mut object, &mut others, minus);
+                    let relocation = Relocation {
+                        offset: rel.1.at as u64,
+                        size: typ.size,
+                        kind,
+                        encoding: typ.encoding,
+                        symbol,
+                        addend: 0,
+                    };
+                    object.add_relocation(rel.0, relocation)?;
+                } else {
+                    // Pc-relative values are taken from their own location, not from the subtracted symbol
+                    match positions.get(minus) {
+                        Some((section, at)) if *section == rel.0 => typ.addend += rel.1.at as i64 - *at as i64,
+                        _ => {
+                            return Err(format!("Can't subtract '{}', it's not in the section of the value", minus).into())
+                        }
+                    }
+                }
+            }
+
+            // Like GNU as, labels in sections the linker merges keep their symbol if there's an addend, the linker
+            // can't tell which string the section's symbol plus an offset points into otherwise
+            let addend = typ.addend + rel.1.addend;
+            let (to, offset) = match positions.get(&rel.1.to) {
+                Some(&(section, at)) if locals.contains_key(&rel.1.to) && addend != 0 && merged.contains(&section) => {
+                    let symbol = *kept.entry(rel.1.to.clone()).or_insert_with(|| {
+                        let symbol = Symbol {
+                            name: rel.1.to.clone().into_bytes(),
+                            value: at as u64,
+                            size: 0,
+                            kind: SymbolKind::Label,
+                            scope: SymbolScope::Compilation,
+                            weak: false,
+                            section: SymbolSection::Section(section),
+                            flags: SymbolFlags::None,
+                        };
+                        object.add_symbol(symbol)
+                    });
+                    (symbol, 0)
+                }
+                _ => (to, offset),
             };
 
             let relocation = Relocation {
                 offset: rel.1.at as u64,
-                size: 32, // Todo: This is hardcoded atm
-                kind,
-                encoding,
+                size: typ.size,
+                kind: typ.kind,
+                encoding: typ.encoding,
                 symbol: to,
-                addend: match object_type {
-                    ObjectFileType::Elf => 0,
-                    ObjectFileType::MachO => -4,
-                },
+                addend: addend + offset,
             };
 
             object.add_relocation(rel.0, relocation)?;
         }
 
-        object.write_stream(file)?;
-
-        Ok(())
+        Ok(object.write()?)
     }
 }
@@ -4,38 +4,216 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use std::io::Write;
+use std::collections::HashMap;
 use crate::assembler::label::LabelMap;
-use crate::instructions::{Instruction, Reference};
+use crate::instructions::{Encodable, Reference, Subtrahend};
+use crate::parse::helpers::Relativity;
+use crate::parse::{DataValue, SectionAttributes};
+
+/// Whether a value fits the fixup of a reference. Data and immediates the CPU doesn't sign extend can be written
+/// unsigned, like `.byte 255` or `add al, 200`, values it sign extends can't
+fn fits(reference: &Reference, value: i64) -> bool {
+    let bits = reference.size as u32;
+    let min = -1i128 << (bits - 1);
+    let max = if reference.signed { (1i128 << (bits - 1)) - 1 } else { (1i128 << bits) - 1 };
+    (min..=max).contains(&(value as i128))
+}
 
 pub struct Section {
     pub bytes: Vec<u8>,
     pub references: Vec<Reference>,
     pub label_map: LabelMap,
+    /// The largest boundary the section was aligned to, which it needs in the object file
+    pub align: u64,
+    /// Whether the section is uninitialized, like `.bss`, so it only has a size and no bytes
+    pub nobits: bool,
+    /// Whether anything but zeros was written to an uninitialized section, which it can't hold
+    pub initialized: bool,
+    /// The flags and type of an ELF section, if `.section` gave them
+    pub attributes: Option<SectionAttributes>,
+    size: usize,
 }
 
 impl Section {
-    pub fn new() -> Self {
-        Section { bytes: Vec::new(), references: Vec::new(), label_map: LabelMap::new() }
+    pub fn new(nobits: bool) -> Self {
+        Section {
+            bytes: Vec::new(),
+            references: Vec::new(),
+            label_map: LabelMap::new(),
+            align: 1,
+            nobits,
+            initialized: false,
+            attributes: None,
+            size: 0,
+        }
     }
 
     pub fn at(&self) -> usize {
-        self.bytes.len()
+        if self.nobits {
+            self.size
+        } else {
+            self.bytes.len()
+        }
     }
 
-    pub fn write_instruction(&mut self, instruction: &Instruction) {
+    pub fn write_instruction(&mut self, instruction: &dyn Encodable) -> Result<(), String> {
         let reloc_offset = self.bytes.len();
 
-        let encoded = instruction.encode();
+        let encoded = instruction.encode()?;
+        if self.nobits {
+            self.initialized = true;
+            self.size += encoded.get_bytes().len();
+            return Ok(());
+        }
+
         self.bytes.extend(encoded.get_bytes());
         for r in encoded.get_refs() {
-            let new_r = Reference { to: r.to.clone(), at: r.at + reloc_offset, rel: r.rel };
+            let new_r = Reference {
+                to: r.to.clone(),
+                minus: r.minus.clone(),
+                addend: r.addend,
+                at: r.at + reloc_offset,
+                rel: r.rel,
+                specifier: r.specifier,
+                size: r.size,
+                signed: r.signed,
+                to_end: r.to_end,
+                relax: r.relax,
+            };
             self.references.push(new_r);
         }
+        Ok(())
+    }
+
+    pub fn write_value(&mut self, size: usize, value: DataValue) {
+        let value = match value {
+            DataValue::Integer(n) => return self.write_bytes(&n.to_le_bytes()[..size]),
+            DataValue::Reference(value) => value,
+        };
+        let minus = match value.minus {
+            Some(minus) if minus == "." => Some(Subtrahend::Location),
+            minus => minus.map(Subtrahend::Symbol),
+        };
+        if self.nobits {
+            self.initialized = true;
+            self.size += size;
+            return;
+        }
+
+        self.references.push(Reference {
+            to: value.symbol.unwrap_or_default(),
+            minus,
+            addend: value.addend,
+            at: self.at(),
+            rel: Relativity::Absolute,
+            specifier: value.specifier,
+            size: size as u8 * 8,
+            signed: false,
+            to_end: 0,
+            relax: None,
+        });
+        self.write_zeros(size);
+    }
+
+    /// Gives the location `.` stands for in the references made since the `from`th one a label at `at`, so it's
+    /// resolved like any other symbol
+    pub fn name_location(&mut self, from: usize, at: usize, name: impl FnOnce() -> String) {
+        let here = Subtrahend::Symbol(".".to_string());
+        let uses = |r: &Reference| r.to == "." || r.minus.as_ref() == Some(&here);
+        if !self.references[from..].iter().any(uses) {
+            return;
+        }
+
+        let name = name();
+        for r in &mut self.references[from..] {
+            if r.to == "." {
+                r.to = name.clone();
+            }
+            if r.minus.as_ref() == Some(&here) {
+                r.minus = Some(Subtrahend::Symbol(name.clone()));
+            }
+        }
+        self.label_map.insert_label(name, at);
     }
 
-    pub fn write_string(&mut self, string: String) {
-        self.bytes.write(string.as_bytes()).unwrap();
-        self.bytes.write(&[0]).unwrap();
+    /// Writes differences of two labels in the same section, which don't need a relocation. `labels` has the
+    /// section and location of every label
+    pub fn fold_differences(&mut self, name: &str, labels: &HashMap<String, (String, usize)>) -> Result<(), String> {
+        let bytes = &mut self.bytes;
+        let mut result = Ok(());
+        self.references.retain(|r| {
+            let position = |symbol: &str| labels.get(symbol).map(|(section, at)| (section.as_str(), *at));
+            let minus = match &r.minus {
+                Some(Subtrahend::Symbol(symbol)) => position(symbol),
+                Some(Subtrahend::Location) => Some((name, r.at)),
+                None => return true,
+            };
+
+            match (position(&r.to), minus) {
+                (Some((to_section, to)), Some((minus_section, minus))) if to_section == minus_section => {
+                    let value = to as i64 - minus as i64 + r.addend;
+                    if !fits(r, value) {
+                        result = Err(format!("Difference {} of '{}' doesn't fit in {} bits", value, r.to, r.size));
+                    }
+
+                    let size = r.size as usize / 8;
+                    bytes[r.at..r.at + size].copy_from_slice(&value.to_le_bytes()[..size]);
+                    false
+                }
+                _ => true,
+            }
+        });
+        result
+    }
+
+    /// Writes values of constants defined with `.set` or `.equ` after their use, which don't need a relocation
+    pub fn fold_constants(&mut self, constants: &HashMap<String, i64>) -> Result<(), String> {
+        let bytes = &mut self.bytes;
+        let mut result = Ok(());
+        self.references.retain(|r| {
+            let value = match constants.get(&r.to) {
+                Some(value) if r.rel == Relativity::Absolute && r.minus.is_none() && r.specifier.is_none() => {
+                    value.wrapping_add(r.addend)
+                }
+                _ => return true,
+            };
+
+            if !fits(r, value) {
+                result = Err(format!("Value {} of '{}' doesn't fit in {} bits", value, r.to, r.size));
+            }
+
+            let size = r.size as usize / 8;
+            bytes[r.at..r.at + size].copy_from_slice(&value.to_le_bytes()[..size]);
+            false
+        });
+        result
+    }
+
+    /// Pads to a multiple of `align` bytes, unless that takes more than `max_skip` bytes. Like GNU as, the section is
+    /// aligned to the boundary even if the padding was skipped
+    pub fn align(&mut self, align: u64, max_skip: Option<usize>, padding: impl FnOnce(usize) -> Vec<u8>) {
+        self.align = self.align.max(align);
+
+        let count = (align - self.at() as u64 % align) % align;
+        if max_skip.is_none_or(|max| count as usize <= max) {
+            self.write_bytes(&padding(count as usize));
+        }
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        if self.nobits {
+            self.initialized |= bytes.iter().any(|b| *b != 0);
+            self.size += bytes.len();
+        } else {
+            self.bytes.extend_from_slice(bytes);
+        }
+    }
+
+    pub fn write_zeros(&mut self, count: usize) {
+        if self.nobits {
+            self.size += count;
+        } else {
+            self.bytes.resize(self.bytes.len() + count, 0);
+        }
     }
 }
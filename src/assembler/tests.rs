@@ -0,0 +1,128 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+#[cfg(test)]
+mod tests {
+    use crate::assembler::{Module, ObjectFileType};
+    use crate::parse::parser::parse_lines;
+    use crate::target::X86_64;
+    use object::{elf, Object, ObjectSection, ObjectSymbol, RelocationTarget, SectionFlags, SectionKind};
+
+    fn assemble(code: &str) -> Vec<u8> {
+        let mut target = X86_64::default();
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut target).unwrap();
+        let module = Module::from_lines(lines, &target, ObjectFileType::Elf).unwrap();
+        module.into_object_file(ObjectFileType::Elf, &target).unwrap()
+    }
+
+    /// The symbol and addend of each relocation in a section
+    fn relocations(bytes: &[u8], section: &str) -> Vec<(String, i64)> {
+        let file = object::File::parse(bytes).unwrap();
+        let section = file.section_by_name(section).unwrap();
+        section
+            .relocations()
+            .map(|(_, rel)| {
+                let symbol = match rel.target() {
+                    RelocationTarget::Symbol(index) => file.symbol_by_index(index).unwrap(),
+                    target => panic!("Relocation against {:?}", target),
+                };
+                // Section symbols have no name of their own
+                let section = symbol.section_index().map(|index| file.section_by_index(index).unwrap());
+                let name = match section {
+                    Some(section) if symbol.name() == Ok("") => section.name().unwrap().to_string(),
+                    _ => symbol.name().unwrap().to_string(),
+                };
+                (name, rel.addend())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merged_section_labels() {
+        let strings = ".section .rodata.str1.1,\"aMS\",@progbits,1\n.LC0:\n.string \"10\"\n.LC1:\n.string \"ccc\"\n";
+        let uses = ".text\nlea rdi, [rip + .LC0]\nlea rsi, [rip + .LC1]\n.data\n.quad .LC1\n.quad .LC1+1\n";
+        let code = format!(".intel_syntax noprefix\n{}{}", strings, uses);
+        let bytes = assemble(&code);
+
+        // Like GNU as, the labels keep their symbol when there's an addend, which is pc-relative here
+        let text = vec![(".LC0".to_string(), -4), (".LC1".to_string(), -4)];
+        assert_eq!(relocations(&bytes, ".text"), text);
+        let data = vec![(".rodata.str1.1".to_string(), 3), (".LC1".to_string(), 1)];
+        assert_eq!(relocations(&bytes, ".data"), data);
+
+        // Other local labels are relocated from their section, and aren't symbols
+        let bytes = assemble(".intel_syntax noprefix\n.data\n.byte 0\n.LC0:\n.byte 1\n.text\nlea rdi, [rip + .LC0]\n");
+        assert_eq!(relocations(&bytes, ".text"), vec![(".data".to_string(), -3)]);
+        let file = object::File::parse(&*bytes).unwrap();
+        assert!(file.symbols().all(|symbol| symbol.name() != Ok(".LC0")));
+    }
+
+    #[test]
+    fn test_section_attributes() {
+        let code = ".section .init_array,\"aw\"\n.quad 0\n.section .rodata.cst8,\"aM\",@progbits,8\n.quad 1\n\
+                    .section .tbss.x,\"awT\",@nobits\n.zero 4\n.section .note.GNU-stack,\"\",@progbits\n";
+        let bytes = assemble(code);
+        let file = object::File::parse(&*bytes).unwrap();
+        let section = |name: &str| {
+            let section = file.section_by_name(name).unwrap();
+            match section.flags() {
+                SectionFlags::Elf { sh_flags } => (section.kind(), sh_flags as u32),
+                flags => panic!("{:?} aren't ELF flags", flags),
+            }
+        };
+
+        // The type comes from the name if it isn't given, like in GNU as
+        let init_array = (SectionKind::Elf(elf::SHT_INIT_ARRAY), elf::SHF_ALLOC | elf::SHF_WRITE);
+        assert_eq!(section(".init_array"), init_array);
+        // Constants are written unmerged, there's no entry size for them
+        assert_eq!(section(".rodata.cst8"), (SectionKind::ReadOnlyData, elf::SHF_ALLOC));
+        let tbss = (SectionKind::UninitializedTls, elf::SHF_ALLOC | elf::SHF_WRITE | elf::SHF_TLS);
+        assert_eq!(section(".tbss.x"), tbss);
+        assert_eq!(section(".note.GNU-stack"), (SectionKind::Other, 0));
+    }
+
+    #[test]
+    fn test_tls_specifiers() {
+        let tdata = ".section .tdata,\"awT\",@progbits\ncounter:\n.long 1\n";
+        let code = format!("{}.text\nmov eax, dword ptr fs:[counter@tpoff]\n", tdata);
+        let bytes = assemble(&code);
+        let file = object::File::parse(&*bytes).unwrap();
+        let flags = file.section_by_name(".tdata").unwrap().flags();
+        assert!(matches!(flags, SectionFlags::Elf { sh_flags } if sh_flags & elf::SHF_TLS as u64 != 0));
+        assert_eq!(relocations(&bytes, ".text"), vec![("counter".to_string(), 0)]);
+
+        // The offset from the thread pointer doesn't exist for other variables
+        let code = ".data\nplain:\n.long 1\n.text\nmov eax, dword ptr fs:[plain@tpoff]\n".to_string();
+        let mut target = X86_64::default();
+        let lines = parse_lines("test.s".to_string(), code, &mut target).unwrap();
+        let module = Module::from_lines(lines, &target, ObjectFileType::Elf).unwrap();
+        assert!(module.into_object_file(ObjectFileType::Elf, &target).is_err());
+    }
+
+    #[test]
+    fn test_unsigned_constants() {
+        // The value is checked when the instruction is parsed if it's defined before, and when it's folded otherwise
+        for code in [".equ E, 200\nadd al, E\n", "add al, E\n.equ E, 200\n"] {
+            let bytes = assemble(code);
+            let file = object::File::parse(&*bytes).unwrap();
+            assert_eq!(file.section_by_name(".text").unwrap().data(), Ok(&[0x04u8, 0xC8][..]));
+        }
+    }
+
+    #[test]
+    fn test_common_symbols() {
+        let bytes = assemble(".local x\n.comm x,4,4\n.comm y,8,8\n");
+        let file = object::File::parse(&*bytes).unwrap();
+        let symbol = |name: &str| file.symbols().find(|symbol| symbol.name() == Ok(name)).unwrap();
+
+        // Like GNU as, local ones are reserved in .bss, which is how GCC writes zeroed statics
+        let x = symbol("x");
+        let bss = file.section_by_name(".bss").unwrap().index();
+        assert_eq!((x.section_index(), x.is_local(), x.size()), (Some(bss), true, 4));
+        let y = symbol("y");
+        assert_eq!((y.is_common(), y.is_global(), y.size()), (true, true, 8));
+    }
+}
@@ -2,10 +2,20 @@
 
 use std::process::exit;
 use crate::ObjectFileType;
+use crate::parse::Syntax;
+use crate::target::{target_from_name, Target, TARGETS};
 
 #[derive(Default)]
 pub struct CommandLine {
     pub format: ObjectFileType,
+    pub target: Box<dyn Target>,
+    pub instr_tables: Vec<String>,
+    pub march: Option<String>,
+    pub syntax: Option<Syntax>,
+    pub default_rel: bool,
+    pub relax_relocations: Option<bool>,
+    pub report_features: bool,
+    pub list_instructions: Option<Option<String>>,
     pub input: String,
     pub output: String,
 }
@@ -18,6 +28,32 @@ fn help(argv1: String) -> ! {
     println!("      elf           ELF (64-bit)");
     println!("      macho         Mach-O");
     println!("   -o filename  Set output filename");
+    println!("   --instr-table file");
+    println!("                Load extra instructions from a file in the x86-csv format");
+    println!("   --march=level");
+    println!("                Only accept instructions available on a CPU level");
+    println!("      x86-64        Baseline x86-64");
+    println!("      x86-64-v2     Adds POPCNT, SSE3, SSSE3, SSE4.1 and SSE4.2");
+    println!("      x86-64-v3     Adds AVX, AVX2, BMI1, BMI2, F16C, FMA, LZCNT and MOVBE");
+    println!("      x86-64-v4     Adds AVX-512");
+    println!("      all           Every instruction (default)");
+    println!("   --syntax=syntax");
+    println!("                Set the syntax instructions are written in, until .att_syntax or .intel_syntax");
+    println!("      intel         Intel (default)");
+    println!("      att           AT&T");
+    println!("      nasm          NASM, the default for .asm and .nasm files");
+    println!("   --default-rel");
+    println!("                Make memory operands with only a symbol rip-relative, except [abs symbol]");
+    println!("   -mrelax-relocations=yes|no");
+    println!("                Use relocations for GOT loads that the linker can relax (default yes)");
+    println!("   --report-features");
+    println!("                Print the CPU features used by the input");
+    println!("   --list-instructions[=mnemonic]");
+    println!("                List the forms of every instruction, or of one, and exit");
+    println!("   -t target    Set the target architecture");
+    for target in TARGETS {
+        println!("      {}", target);
+    }
 
     exit(0);
 }
@@ -48,6 +84,45 @@ pub fn parse_command_line() -> CommandLine {
             } else {
                 panic!("Used -f without specifying a format");
             }
+        } else if arg == "--instr-table" {
+            if !args.is_empty() {
+                cline.instr_tables.push(args.remove(0));
+            } else {
+                panic!("Used --instr-table without specifying a file");
+            }
+        } else if let Some(march) = arg.strip_prefix("--march=") {
+            cline.march = Some(march.to_string());
+        } else if let Some(syntax) = arg.strip_prefix("--syntax=") {
+            cline.syntax = Some(match syntax {
+                "intel" => Syntax::Intel,
+                "att" => Syntax::Att,
+                "nasm" => Syntax::Nasm,
+                _ => panic!("Invalid syntax '{}'", syntax)
+            });
+        } else if arg == "--default-rel" {
+            cline.default_rel = true;
+        } else if let Some(relax) = arg.strip_prefix("-mrelax-relocations=") {
+            cline.relax_relocations = Some(match relax {
+                "yes" => true,
+                "no" => false,
+                _ => panic!("Invalid value '{}' for -mrelax-relocations", relax)
+            });
+        } else if arg == "--report-features" {
+            cline.report_features = true;
+        } else if arg == "--list-instructions" {
+            cline.list_instructions = Some(None);
+        } else if let Some(mnemonic) = arg.strip_prefix("--list-instructions=") {
+            cline.list_instructions = Some(Some(mnemonic.to_string()));
+        } else if arg == "-t" {
+            if !args.is_empty() {
+                let target = args.remove(0);
+                cline.target = match target_from_name(&target) {
+                    Some(target) => target,
+                    None => panic!("Invalid target '{}'", target)
+                }
+            } else {
+                panic!("Used -t without specifying a target");
+            }
         } else if arg == "-o" {
             if !args.is_empty() {
                 if cline.output.is_empty() {
@@ -67,7 +142,7 @@ pub fn parse_command_line() -> CommandLine {
         }
     }
 
-    if cline.input.is_empty() {
+    if cline.input.is_empty() && cline.list_instructions.is_none() {
         help(program_name);
     }
 
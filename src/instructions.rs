@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 use crate::number::Number;
-use crate::parse::helpers::{Immediate, ImmediateType, Relativity};
+use crate::parse::helpers::{Immediate, ImmediateType, Relativity, Specifier};
 use std::fmt::{Debug, Formatter};
 use std::io::Write;
 use std::str::FromStr;
@@ -46,13 +46,13 @@ impl TryFrom<i32> for Register {
             6 => Ok(Si),
             7 => Ok(Di),
             8 => Ok(R8),
-            10 => Ok(R9),
-            11 => Ok(R10),
-            12 => Ok(R11),
-            13 => Ok(R12),
-            14 => Ok(R13),
-            15 => Ok(R14),
-            16 => Ok(R15),
+            9 => Ok(R9),
+            10 => Ok(R10),
+            11 => Ok(R11),
+            12 => Ok(R12),
+            13 => Ok(R13),
+            14 => Ok(R14),
+            15 => Ok(R15),
             _ => Err(()),
         }
     }
@@ -62,21 +62,25 @@ impl FromStr for Register {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if ["ah", "al", "ax", "eax", "rax"].contains(&s) {
+        let s = s.to_ascii_lowercase();
+        let s = s.as_str();
+
+        // The high byte registers share their numbers with spl, bpl, sil and dil, which need a REX prefix instead
+        if ["al", "ax", "eax", "rax"].contains(&s) {
             Ok(Ax)
-        } else if ["bh", "bl", "bx", "ebx", "rbx"].contains(&s) {
+        } else if ["bl", "bx", "ebx", "rbx"].contains(&s) {
             Ok(Bx)
-        } else if ["ch", "cl", "cx", "ecx", "rcx"].contains(&s) {
+        } else if ["cl", "cx", "ecx", "rcx"].contains(&s) {
             Ok(Cx)
-        } else if ["dh", "dl", "dx", "edx", "rdx"].contains(&s) {
+        } else if ["dl", "dx", "edx", "rdx"].contains(&s) {
             Ok(Dx)
-        } else if ["sil", "si", "esi", "rsi"].contains(&s) {
+        } else if ["dh", "sil", "si", "esi", "rsi"].contains(&s) {
             Ok(Si)
-        } else if ["dil", "di", "edi", "rdi"].contains(&s) {
+        } else if ["bh", "dil", "di", "edi", "rdi"].contains(&s) {
             Ok(Di)
-        } else if ["spl", "sp", "esp", "rsp"].contains(&s) {
+        } else if ["ah", "spl", "sp", "esp", "rsp"].contains(&s) {
             Ok(Sp)
-        } else if ["bpl", "bp", "ebp", "rbp"].contains(&s) {
+        } else if ["ch", "bpl", "bp", "ebp", "rbp"].contains(&s) {
             Ok(Bp)
         } else if ["r8b", "r8w", "r8d", "r8"].contains(&s) {
             Ok(R8)
@@ -110,7 +114,6 @@ pub enum Mod {
     NoDereference = 0b11,
 }
 
-use crate::parse::helpers::Relativity::RipRelative;
 use Mod::*;
 
 #[derive(Copy, Clone, Debug, Eq, PartialOrd, PartialEq)]
@@ -156,8 +159,28 @@ impl TryFrom<String> for Size {
 #[derive(Debug)]
 pub enum Opcode {
     Byte(u8),
+    /// A legacy prefix, which has to come before REX
+    Prefix(u8),
     Rex { wide: bool },
-    // ModPart(u8),
+    /// An opcode byte with the low bits of a register added to it
+    PlusReg(u8, Register),
+}
+
+/// The base and scaled index of a memory operand, encoded in a SIB byte
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sib {
+    pub base: Option<Register>,
+    pub index: Option<Register>,
+    /// log2 of the scale
+    pub scale: u8,
+}
+
+/// Where ModRM, SIB and the displacement end up, shared by `encode` and `encoded_len` so they always agree
+struct Addressing {
+    mod_rm: Option<u8>,
+    sib: Option<u8>,
+    /// Size of the displacement in bytes
+    disp: usize,
 }
 
 #[derive(Debug)]
@@ -170,12 +193,64 @@ pub struct Instruction {
     rm: Option<Register>,
     imm1: Option<Immediate>,
     imm2: Option<Immediate>,
+    feature: Option<&'static str>,
+    sib: Option<Sib>,
+    /// Segment override prefix
+    segment: Option<u8>,
+    /// Forces a REX prefix, so spl, bpl, sil and dil can be addressed
+    rex: bool,
+}
+
+/// What's encoded after the opcode of an instruction, the fields of ModRM, the displacement and the immediates
+#[derive(Debug)]
+pub struct Operands {
+    pub r#mod: Mod,
+    pub offset: Option<Immediate>,
+    pub reg: Option<Register>,
+    pub rm: Option<Register>,
+    pub imm1: Option<Immediate>,
+    pub imm2: Option<Immediate>,
+}
+
+impl Operands {
+    /// Only immediates, for instructions without ModRM
+    pub fn immediates(imm1: Option<Immediate>, imm2: Option<Immediate>) -> Self {
+        Operands { r#mod: NoDereference, offset: None, reg: None, rm: None, imm1, imm2 }
+    }
 }
 
+/// A relocatable expression made by an instruction or data, a symbol or the difference of a symbol and another one
 pub struct Reference {
     pub to: String,
+    /// Subtracted from `to`, like `.LJTI0_0` in `.long .LBB0_3-.LJTI0_0`
+    pub minus: Option<Subtrahend>,
+    /// Added to the value, like the 16 in `call foo+16`
+    pub addend: i64,
     pub at: usize,
     pub rel: Relativity,
+    pub specifier: Option<Specifier>,
+    /// Width of the fixup in bits
+    pub size: u8,
+    /// Whether the CPU sign extends the value, like immediates and displacements, unlike data
+    pub signed: bool,
+    /// Bytes from the start of the fixup to the end of the instruction, which pc-relative addends have to make up for
+    pub to_end: usize,
+    /// Set when the linker may rewrite the instruction, like a GOT load into a lea when the symbol turns out local
+    pub relax: Option<Relax>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Subtrahend {
+    Symbol(String),
+    /// The location of the reference itself, the `.` in `sym - .`
+    Location,
+}
+
+/// How an instruction can be relaxed, which depends on whether it has a REX prefix
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Relax {
+    Plain,
+    Rex,
 }
 
 pub struct EncodedInstruction {
@@ -184,6 +259,17 @@ pub struct EncodedInstruction {
     refs: Vec<Reference>,
 }
 
+/// An instruction of any target, that can be turned into bytes
+pub trait Encodable: Debug {
+    /// Fails if an integer doesn't fit the field it's written to
+    fn encode(&self) -> Result<EncodedInstruction, String>;
+
+    /// The CPU feature needed to execute this instruction
+    fn feature(&self) -> Option<&'static str> {
+        None
+    }
+}
+
 fn to_byte(reg: &Option<Register>) -> u8 {
     if reg == &Some(Rip) {
         0b101
@@ -193,53 +279,214 @@ fn to_byte(reg: &Option<Register>) -> u8 {
 }
 
 impl Instruction {
-    pub fn new(
-        name: String,
-        r#mod: Mod,
-        opcode: Vec<Opcode>,
-        offset: Option<Immediate>,
-        reg: Option<Register>,
-        rm: Option<Register>,
-        imm1: Option<Immediate>,
-        imm2: Option<Immediate>,
-    ) -> Self {
-        Instruction { name, r#mod, opcode, offset, reg, rm, imm1, imm2 }
+    pub fn new(name: String, opcode: Vec<Opcode>, operands: Operands) -> Self {
+        let Operands { r#mod, offset, reg, rm, imm1, imm2 } = operands;
+        Instruction { name, r#mod, opcode, offset, reg, rm, imm1, imm2, feature: None, sib: None, segment: None, rex: false }
+    }
+
+    pub fn with_memory(mut self, sib: Option<Sib>, segment: Option<u8>) -> Self {
+        self.sib = sib;
+        self.segment = segment;
+        self
+    }
+
+    pub fn with_rex(mut self, rex: bool) -> Self {
+        self.rex = rex;
+        self
+    }
+
+    pub fn with_feature(mut self, feature: Option<&'static str>) -> Self {
+        self.feature = feature;
+        self
+    }
+
+    fn rex(&self) -> Option<u8> {
+        let ext = |reg: Option<Register>| reg.filter(|r| *r != Rip).map(|r| (r as u8 >> 3) & 1).unwrap_or(0);
+        let (index, base) = match self.sib {
+            Some(sib) => (sib.index, sib.base),
+            None => (None, self.rm),
+        };
+        let plus_reg = self.opcode.iter().find_map(|part| match part {
+            Opcode::PlusReg(_, reg) => Some(*reg),
+            _ => None,
+        });
+        let wide = self.opcode.iter().any(|part| matches!(part, Opcode::Rex { wide: true }));
+        let explicit = self.opcode.iter().any(|part| matches!(part, Opcode::Rex { .. }));
+
+        let bits = (wide as u8) << 3 | ext(self.reg) << 2 | ext(index) << 1 | ext(base.or(plus_reg));
+        // Extended registers need a REX prefix, even if the instruction doesn't have one on its own
+        (explicit || self.rex || bits != 0).then_some(0b01000000 | bits)
+    }
+
+    /// Whether a rip-relative load can be relaxed by the linker. These are the mov, call, jmp, test and arithmetic
+    /// instructions GNU as marks
+    fn relax(&self) -> Option<Relax> {
+        if self.rm != Some(Rip) || self.sib.is_some() {
+            return None;
+        }
+
+        let opcode = self.opcode.iter().filter_map(|part| match part {
+            Opcode::Byte(byte) => Some(*byte),
+            _ => None,
+        });
+        let relaxable = match opcode.collect::<Vec<u8>>()[..] {
+            [0xFF] => matches!(to_byte(&self.reg) & 0b111, 2 | 4),
+            [byte] => byte == 0x8B || byte == 0x85 || byte | 0x38 == 0x3B,
+            _ => false,
+        };
+
+        relaxable.then_some(if self.rex().is_some() { Relax::Rex } else { Relax::Plain })
+    }
+
+    fn addressing(&self) -> Addressing {
+        let disp_size = |r#mod: Mod| match r#mod {
+            Offset8Bit => 1,
+            Offset32Bit => 4,
+            _ => 0,
+        };
+        let mod_rm = |r#mod: Mod, rm: u8| (r#mod as u8) << 6 | ((to_byte(&self.reg) & 0b111) << 3) | (rm & 0b111);
+
+        if self.rm.is_none() && self.reg.is_none() {
+            return Addressing { mod_rm: None, sib: None, disp: 0 };
+        }
+
+        if let Some(sib) = self.sib {
+            let index = sib.index.map(|r| r as u8 & 0b111).unwrap_or(0b100);
+            let (r#mod, base, disp) = match sib.base {
+                // Without a base there's always a 32-bit displacement
+                None => (NoOffset, 0b101, 4),
+                Some(base) if self.r#mod == NoOffset && base as u8 & 0b111 == 0b101 => (Offset8Bit, base as u8, 1),
+                Some(base) => (self.r#mod, base as u8, disp_size(self.r#mod)),
+            };
+            let sib = sib.scale << 6 | index << 3 | (base & 0b111);
+            return Addressing { mod_rm: Some(mod_rm(r#mod, 0b100)), sib: Some(sib), disp };
+        }
+
+        if self.rm == Some(Rip) {
+            return Addressing { mod_rm: Some(mod_rm(NoOffset, 0b101)), sib: None, disp: 4 };
+        }
+
+        // [rbp] and [r13] can't be encoded without a displacement, that encoding means rip relative instead
+        let rm = to_byte(&self.rm);
+        let r#mod = if self.r#mod == NoOffset && rm & 0b111 == 0b101 { Offset8Bit } else { self.r#mod };
+        // [rsp] and [r12] need a SIB byte, because their rm value is used to signal one
+        let sib = (r#mod != NoDereference && rm & 0b111 == 0b100).then_some(0b00100100);
+
+        Addressing { mod_rm: Some(mod_rm(r#mod, rm)), sib, disp: disp_size(r#mod) }
+    }
+
+    /// The number of bytes `encode` will produce, without actually encoding anything
+    pub fn encoded_len(&self) -> usize {
+        let addressing = self.addressing();
+        let explicit_rex = self.opcode.iter().any(|part| matches!(part, Opcode::Rex { .. }));
+        let imms = [&self.imm1, &self.imm2].into_iter().filter_map(|imm| imm.as_ref()).map(|imm| 1 << imm.size as usize);
+
+        self.segment.is_some() as usize
+            + self.opcode.len()
+            + (!explicit_rex && self.rex().is_some()) as usize
+            + addressing.mod_rm.is_some() as usize
+            + addressing.sib.is_some() as usize
+            + addressing.disp
+            + imms.sum::<usize>()
+    }
+
+    /// Whether an immediate that depends on a symbol is narrower than 32 bits, which its value rarely fits in
+    pub fn narrow_reference(&self) -> bool {
+        [&self.imm1, &self.imm2].into_iter().flatten().any(|imm| {
+            matches!(imm.typ, ImmediateType::Reference(_)) && matches!(imm.size, Size::Byte | Size::Word)
+        })
+    }
+
+    /// Whether the CPU sign extends an immediate, like in `mov rax, imm32`, `push imm32` and `add eax, imm8`, but not
+    /// in `mov eax, imm32` or `add al, imm8`. Relative ones always are
+    fn sign_extends(&self, imm: &Immediate) -> bool {
+        let opcode = |bytes: &[u8]| self.opcode.iter().any(|part| matches!(part, Opcode::Byte(b) if bytes.contains(b)));
+        let wide = self.rex().is_some_and(|rex| rex & 0b1000 != 0);
+        imm.rel != Relativity::Absolute
+            || (imm.size == Size::DWord && (wide || opcode(&[0x68])))
+            || (imm.size == Size::Byte && opcode(&[0x6A, 0x6B, 0x83]))
+    }
+
+    /// Whether integer immediates fit their size. Ones the CPU sign extends have to be in the signed range, the others
+    /// can be written unsigned too
+    pub fn immediates_fit(&self) -> bool {
+        [&self.imm1, &self.imm2].into_iter().flatten().all(|imm| match imm.typ {
+            ImmediateType::Integer(value) => {
+                let bits = 8 << imm.size as usize;
+                let max = if self.sign_extends(imm) { 1i128 << (bits - 1) } else { 1i128 << bits };
+                (-1i128 << (bits - 1)..max).contains(&(value as i128))
+            }
+            ImmediateType::Reference(_) => true,
+        })
     }
 
-    pub fn encode(&self) -> EncodedInstruction {
+    pub fn encode(&self) -> Result<EncodedInstruction, String> {
         let mut encoded = EncodedInstruction::new(self.name.clone());
+        let mut rex = self.rex();
+
+        if let Some(segment) = self.segment {
+            encoded.write_byte(segment);
+        }
 
         for part in &self.opcode {
             match part {
-                Opcode::Byte(byte) => encoded.write_byte(*byte),
-                Opcode::Rex { wide } => {
-                    let rm_bw: u8 = (to_byte(&self.rm) & 0b1000) >> 1;
-                    let reg_bw: u8 = (to_byte(&self.reg) & 0b1000) >> 3;
-                    let rex: u8 = 0b01000000 | (*wide as u8) << 3 | rm_bw | reg_bw;
-                    encoded.write_byte(rex);
-                } //Opcode::ModPart(_) => {}
+                Opcode::Prefix(byte) => encoded.write_byte(*byte),
+                Opcode::Rex { .. } => encoded.write_byte(rex.take().unwrap()),
+                Opcode::Byte(byte) | Opcode::PlusReg(byte, _) => {
+                    // An inferred REX goes right before the opcode
+                    if let Some(rex) = rex.take() {
+                        encoded.write_byte(rex);
+                    }
+                    match part {
+                        Opcode::PlusReg(byte, reg) => encoded.write_byte(byte + (*reg as u8 & 0b111)),
+                        _ => encoded.write_byte(*byte),
+                    }
+                }
             }
         }
 
-        if self.rm.is_some() || self.reg.is_some() {
-            encoded.write_mod(self.r#mod, to_byte(&self.rm), to_byte(&self.reg));
+        let addressing = self.addressing();
+        if let Some(mod_rm) = addressing.mod_rm {
+            encoded.write_byte(mod_rm);
+        }
+        if let Some(sib) = addressing.sib {
+            encoded.write_byte(sib);
         }
 
-        if self.offset.is_some() {
-            if self.r#mod == Offset32Bit || self.offset.as_ref().unwrap().rel == RipRelative {
-                encoded.write_imm::<i32, [u8; 4]>(self.offset.as_ref().unwrap());
-            } else if self.r#mod == Offset8Bit {
-                encoded.write_imm::<i8, [u8; 1]>(self.offset.as_ref().unwrap());
+        let zero = Immediate::integer(0, Relativity::Absolute, Size::DWord);
+        let offset = self.offset.as_ref().unwrap_or(&zero);
+        let written = match addressing.disp {
+            // Displacements are sign extended to the size of an address
+            1 => encoded.write_imm::<i8, [u8; 1]>(offset, true),
+            4 => encoded.write_imm::<i32, [u8; 4]>(offset, true),
+            _ => Ok(()),
+        };
+        written.and_then(|_| {
+            let mut immediates = self.imm1.iter().chain(&self.imm2);
+            immediates.try_for_each(|imm| encoded.write_immediate(imm, self.sign_extends(imm)))
+        })?;
+
+        // Immediates can follow a displacement, so the distance to the end is only known now
+        let len = encoded.bytes.len();
+        let relax = self.relax();
+        encoded.refs.iter_mut().for_each(|r| {
+            r.to_end = len - r.at;
+            if r.rel == Relativity::RipRelative {
+                r.relax = relax;
             }
-        }
-        if self.imm1.is_some() {
-            encoded.write_immediate(self.imm1.as_ref().unwrap());
-        }
-        if self.imm2.is_some() {
-            encoded.write_immediate(self.imm2.as_ref().unwrap());
-        }
+        });
+
+        Ok(encoded)
+    }
+}
 
-        encoded
+impl Encodable for Instruction {
+    fn encode(&self) -> Result<EncodedInstruction, String> {
+        Instruction::encode(self)
+    }
+
+    fn feature(&self) -> Option<&'static str> {
+        self.feature
     }
 }
 
@@ -262,6 +509,10 @@ impl EncodedInstruction {
         &self.refs
     }
 
+    pub fn add_reference(&mut self, reference: Reference) {
+        self.refs.push(reference);
+    }
+
     pub fn write_byte(&mut self, byte: u8) {
         self.bytes.push(byte);
     }
@@ -270,31 +521,49 @@ impl EncodedInstruction {
         self.bytes.write(value.to_bytes(false).as_ref()).unwrap();
     }
 
-    pub fn write_imm<'a, I: Number<Output = O> + From<i8> + TryFrom<i32>, O: AsRef<[u8]>>(&mut self, imm: &Immediate) {
+    /// Writes an immediate, `signed` tells whether the CPU sign extends it
+    pub fn write_imm<I: Number<Output = O> + From<i8> + TryFrom<i64>, O: AsRef<[u8]>>(
+        &mut self,
+        imm: &Immediate,
+        signed: bool,
+    ) -> Result<(), String> {
         let at = self.bytes.len();
         match &imm.typ {
             ImmediateType::Integer(i) => {
-                // The unsafe block helps avoid needing Debug for I's TryInto error
-                self.write_num::<I, O>(unsafe { (*i).try_into().unwrap_unchecked() });
+                let size = std::mem::size_of::<O>() * 8;
+                // Values the CPU doesn't sign extend can be written unsigned, like the 200 in `add al, 200`
+                let unsigned = !signed && (1i128 << (size - 1)..1i128 << size).contains(&(*i as i128));
+                let wrapped = if unsigned { (*i as i128 - (1i128 << size)) as i64 } else { *i };
+                let value = I::try_from(wrapped).map_err(|_| format!("Immediate {} doesn't fit in {} bits", i, size))?;
+                self.write_num::<I, O>(value);
             }
-            ImmediateType::Reference(r) => {
+            ImmediateType::Reference(value) => {
                 self.write_num::<I, O>(0.into());
-                self.refs.push(Reference { to: r.to_string(), at, rel: imm.rel });
+                let size = std::mem::size_of::<O>();
+                self.refs.push(Reference {
+                    to: value.symbol.clone().unwrap_or_default(),
+                    minus: value.minus.clone().map(Subtrahend::Symbol),
+                    addend: value.addend,
+                    at,
+                    rel: imm.rel,
+                    specifier: value.specifier,
+                    size: size as u8 * 8,
+                    signed,
+                    to_end: size,
+                    relax: None,
+                });
             }
         }
+        Ok(())
     }
 
-    pub fn write_immediate(&mut self, imm: &Immediate) {
+    pub fn write_immediate(&mut self, imm: &Immediate, signed: bool) -> Result<(), String> {
         match &imm.size {
-            Size::Byte => self.write_imm::<i8, [u8; 1]>(imm),
-            Size::Word => self.write_imm::<i16, [u8; 2]>(imm),
-            Size::DWord => self.write_imm::<i32, [u8; 4]>(imm),
-            Size::QWord => self.write_imm::<i64, [u8; 8]>(imm),
-        };
+            Size::Byte => self.write_imm::<i8, [u8; 1]>(imm, signed),
+            Size::Word => self.write_imm::<i16, [u8; 2]>(imm, signed),
+            Size::DWord => self.write_imm::<i32, [u8; 4]>(imm, signed),
+            Size::QWord => self.write_imm::<i64, [u8; 8]>(imm, signed),
+        }
     }
 
-    fn write_mod(&mut self, r#mod: Mod, rm: u8, reg: u8) {
-        let mod_rm = (r#mod as u8) << 6 | ((reg & 0b111) << 3) | (rm & 0b111);
-        self.write_byte(mod_rm);
-    }
 }
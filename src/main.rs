@@ -10,6 +10,7 @@ extern crate enum_derive;
 use crate::assembler::{Module, ObjectFileType};
 use crate::cli::parse_command_line;
 use crate::parse::parser::parse_lines;
+use crate::parse::{nasm, Syntax};
 use std::ffi::OsStr;
 use std::fs::File;
 use std::io::Read;
@@ -21,11 +22,61 @@ mod cli;
 mod instructions;
 mod number;
 mod parse;
+mod target;
 
 fn main() {
-    let cline = parse_command_line();
+    let mut cline = parse_command_line();
 
+    for table in &cline.instr_tables {
+        let csv = std::fs::read_to_string(table).unwrap_or_else(|e| panic!("Couldn't read {}: {}", table, e));
+        if let Err(e) = cline.target.load_instruction_table(&csv) {
+            eprintln!("{}: {}", table, e);
+            exit(1);
+        }
+    }
+
+    if let Some(march) = &cline.march {
+        if let Err(e) = cline.target.set_arch(march) {
+            eprintln!("{}", e);
+            exit(1);
+        }
+    }
+
+    // NASM sources are usually named .asm, GNU as ones .s
     let path = Path::new(&cline.input);
+    let nasm_extension = path.extension().is_some_and(|e| e == OsStr::new("asm") || e == OsStr::new("nasm"));
+    if cline.syntax.is_none() && nasm_extension {
+        cline.syntax = Some(Syntax::Nasm);
+    }
+
+    if let Some(syntax) = cline.syntax {
+        if let Err(e) = cline.target.set_syntax(syntax) {
+            eprintln!("{}", e);
+            exit(1);
+        }
+    }
+
+    if cline.default_rel {
+        if let Err(e) = cline.target.set_default_rel(true) {
+            eprintln!("{}", e);
+            exit(1);
+        }
+    }
+
+    if let Some(relax) = cline.relax_relocations {
+        if let Err(e) = cline.target.set_relax_relocations(relax) {
+            eprintln!("{}", e);
+            exit(1);
+        }
+    }
+
+    if let Some(mnemonic) = &cline.list_instructions {
+        for (form, description) in cline.target.instruction_forms(mnemonic.as_deref()) {
+            println!("{:<32}{}", form, description);
+        }
+        exit(0);
+    }
+
     if path.extension().unwrap() == OsStr::new("o") {
         eprintln!("Skipping {}, has .o extension", cline.input);
         exit(0);
@@ -35,12 +86,28 @@ fn main() {
 
     let mut code = String::new();
     file.read_to_string(&mut code).unwrap();
-    let parsed_lines = parse_lines(cline.input.clone(), code);
+    let parsed_lines = if cline.syntax == Some(Syntax::Nasm) {
+        nasm::parse_lines(cline.input.clone(), code, &mut *cline.target)
+    } else {
+        parse_lines(cline.input.clone(), code, &mut *cline.target)
+    };
 
     if let Ok(parsed_lines) = parsed_lines {
-        let module = Module::from_lines(parsed_lines);
+        let module = Module::from_lines(parsed_lines, &*cline.target, cline.format).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            exit(1);
+        });
+
+        if cline.report_features {
+            for feature in module.features() {
+                println!("{}", feature);
+            }
+        }
 
-        module.write_to_file(Path::new(&cline.output), ObjectFileType::MachO).expect("Couldn't write module");
+        if let Err(e) = module.write_to_file(Path::new(&cline.output), cline.format, &*cline.target) {
+            eprintln!("{}", e);
+            exit(1);
+        }
     } else {
         exit(1);
     }
@@ -31,3 +31,300 @@ number_for_arithmetic!(i16);
 number_for_arithmetic!(i32);
 number_for_arithmetic!(i64);
 
+/// The binary floating-point formats of `.float`, `.double` and `.tfloat`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FloatFormat {
+    Single,
+    Double,
+    /// The 80-bit format of the x87, which stores the integer bit of the mantissa
+    Extended,
+}
+
+enum FloatValue {
+    Zero,
+    /// `mantissa * 2^exponent`, where the top bit of the mantissa is set, and `inexact` if it's a little more
+    Finite {
+        mantissa: u128,
+        exponent: i64,
+        inexact: bool,
+    },
+    Infinity,
+    NaN,
+}
+
+impl FloatFormat {
+    /// Bits of the mantissa, including the integer bit, and of the exponent
+    fn bits(self) -> (u32, u32) {
+        match self {
+            FloatFormat::Single => (24, 8),
+            FloatFormat::Double => (53, 11),
+            FloatFormat::Extended => (64, 15),
+        }
+    }
+
+    /// Encodes a literal like `1.5e-3`, `-inf`, `nan` or the hex float `0x1.8p3`, rounded to the nearest number of the
+    /// format. Like GNU as, numbers too large for it are an error and too small ones become zero
+    pub fn encode(self, literal: &str) -> Option<Vec<u8>> {
+        let (negative, literal) = match literal.strip_prefix('-') {
+            Some(literal) => (true, literal),
+            None => (false, literal.strip_prefix('+').unwrap_or(literal)),
+        };
+        // GNU as lets float literals start with 0f or 0d
+        let literal = literal.to_ascii_lowercase();
+        let literal = literal.strip_prefix("0f").or_else(|| literal.strip_prefix("0d")).unwrap_or(&literal);
+
+        let value = match literal {
+            "inf" | "infinity" => FloatValue::Infinity,
+            "nan" => FloatValue::NaN,
+            _ => match literal.strip_prefix("0x") {
+                Some(hex) => parse_hex_float(hex)?,
+                None => parse_decimal_float(literal)?,
+            },
+        };
+
+        let (precision, exponent_bits) = self.bits();
+        let max_exponent = (1u64 << exponent_bits) - 1;
+        let (exponent, mantissa) = match value {
+            FloatValue::Zero => (0, 0),
+            FloatValue::Infinity => (max_exponent, 0),
+            // All mantissa bits set, which is what GNU as writes
+            FloatValue::NaN => (max_exponent, u64::MAX >> (64 - precision)),
+            FloatValue::Finite { mantissa, exponent, inexact } => {
+                let bias = (1i64 << (exponent_bits - 1)) - 1;
+                // Numbers too small to be normal have the smallest exponent and fewer bits
+                let biased = exponent + 127 + bias;
+                let shift = (128 - precision) as i64 + (1 - biased).max(0);
+
+                let (mut kept, half, rest) = match shift {
+                    0..=127 => {
+                        let shift = shift as u32;
+                        let below = mantissa & ((1 << shift) - 1);
+                        (mantissa >> shift, below >> (shift - 1) == 1, below & ((1 << (shift - 1)) - 1) != 0 || inexact)
+                    }
+                    128 => (0, true, mantissa << 1 != 0 || inexact),
+                    _ => (0, false, true),
+                };
+                // Round to nearest, ties to even
+                if half && (rest || kept & 1 == 1) {
+                    kept += 1;
+                }
+
+                let mut biased = biased.max(1) as u64;
+                if kept >> precision == 1 {
+                    kept >>= 1;
+                    biased += 1;
+                }
+                if kept >> (precision - 1) == 0 {
+                    biased = 0;
+                }
+                if biased >= max_exponent {
+                    return None;
+                }
+                (biased, kept as u64)
+            }
+        };
+
+        let sign = negative as u64;
+        Some(match self {
+            FloatFormat::Single | FloatFormat::Double => {
+                let fraction = mantissa & ((1 << (precision - 1)) - 1);
+                let bits = sign << (exponent_bits + precision - 1) | exponent << (precision - 1) | fraction;
+                bits.to_le_bytes()[..(exponent_bits + precision) as usize / 8].to_vec()
+            }
+            FloatFormat::Extended => {
+                // The integer bit is set for infinity, and for every number that's not zero or denormal
+                let mantissa = if exponent == max_exponent { mantissa | 1 << 63 } else { mantissa };
+                let mut bytes = mantissa.to_le_bytes().to_vec();
+                bytes.extend_from_slice(&((sign << 15 | exponent) as u16).to_le_bytes());
+                bytes
+            }
+        })
+    }
+}
+
+/// Parses the part of a hex float after `0x`, like `1.8p3`
+fn parse_hex_float(literal: &str) -> Option<FloatValue> {
+    let (digits, exponent) = match literal.split_once('p') {
+        Some((digits, exponent)) => (digits, exponent.parse::<i32>().ok()?),
+        None => (literal, 0),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+    let mut number = Big::default();
+    for digit in integer.chars().chain(fraction.chars()) {
+        number.mul_add(16, digit.to_digit(16)?);
+    }
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    let exponent = exponent as i64 - 4 * fraction.len() as i64;
+    Some(divide(number, Big::from(1), exponent))
+}
+
+/// Parses a decimal float, like `1.5e-3` or `.5`
+fn parse_decimal_float(literal: &str) -> Option<FloatValue> {
+    let (digits, exponent) = match literal.split_once('e') {
+        Some((digits, exponent)) => (digits, exponent.parse::<i32>().ok()?),
+        None => (literal, 0),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+    let mut number = Big::default();
+    for digit in integer.chars().chain(fraction.chars()) {
+        number.mul_add(10, digit.to_digit(10)?);
+    }
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    // Even the extended format only reaches from about 1e-4951 to 1e4932
+    let exponent = exponent as i64 - fraction.len() as i64;
+    let magnitude = exponent + (integer.len() + fraction.len()) as i64;
+    if number.is_zero() {
+        return Some(FloatValue::Zero);
+    } else if magnitude > 5000 {
+        return None;
+    } else if magnitude < -5000 {
+        return Some(FloatValue::Zero);
+    }
+
+    let mut power = Big::from(1);
+    for _ in 0..exponent.abs() {
+        power.mul_add(10, 0);
+    }
+    Some(if exponent >= 0 {
+        number.mul(&power);
+        divide(number, Big::from(1), 0)
+    } else {
+        divide(number, power, 0)
+    })
+}
+
+/// Divides exactly enough to get `numerator / denominator * 2^exponent` to 128 significant bits
+fn divide(mut numerator: Big, mut denominator: Big, exponent: i64) -> FloatValue {
+    if numerator.is_zero() {
+        return FloatValue::Zero;
+    }
+
+    // Line up the top bits, so the first bit of the quotient is the integer bit
+    let (a, b) = (numerator.bits(), denominator.bits());
+    if a > b {
+        denominator.shl(a - b);
+    } else {
+        numerator.shl(b - a);
+    }
+    let mut exponent = exponent + a as i64 - b as i64;
+    if numerator < denominator {
+        numerator.shl(1);
+        exponent -= 1;
+    }
+
+    let mut mantissa = 0u128;
+    for _ in 0..128 {
+        mantissa <<= 1;
+        if numerator >= denominator {
+            numerator.sub(&denominator);
+            mantissa |= 1;
+        }
+        numerator.shl(1);
+    }
+    FloatValue::Finite { mantissa, exponent: exponent - 127, inexact: !numerator.is_zero() }
+}
+
+/// An unsigned integer of any size, little endian and without leading zero words, just enough for exact float
+/// conversions
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Big(Vec<u32>);
+
+impl Big {
+    fn from(n: u32) -> Self {
+        let mut big = Big::default();
+        big.mul_add(1, n);
+        big
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn bits(&self) -> usize {
+        self.0.last().map_or(0, |top| self.0.len() * 32 - top.leading_zeros() as usize)
+    }
+
+    fn mul_add(&mut self, mul: u32, add: u32) {
+        let mut carry = add as u64;
+        for word in &mut self.0 {
+            let n = *word as u64 * mul as u64 + carry;
+            *word = n as u32;
+            carry = n >> 32;
+        }
+        if carry != 0 {
+            self.0.push(carry as u32);
+        }
+        self.trim();
+    }
+
+    fn mul(&mut self, other: &Big) {
+        let mut product = vec![0u32; self.0.len() + other.0.len()];
+        for (i, a) in self.0.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, b) in other.0.iter().enumerate() {
+                let n = product[i + j] as u64 + *a as u64 * *b as u64 + carry;
+                product[i + j] = n as u32;
+                carry = n >> 32;
+            }
+            product[i + other.0.len()] = carry as u32;
+        }
+        self.0 = product;
+        self.trim();
+    }
+
+    fn shl(&mut self, n: usize) {
+        if self.is_zero() {
+            return;
+        }
+        let (words, bits) = (n / 32, n % 32);
+        if bits != 0 {
+            let mut carry = 0;
+            for word in &mut self.0 {
+                let shifted = *word >> (32 - bits);
+                *word = *word << bits | carry;
+                carry = shifted;
+            }
+            if carry != 0 {
+                self.0.push(carry);
+            }
+        }
+        self.0.splice(0..0, std::iter::repeat_n(0, words));
+    }
+
+    /// Subtracts a number that isn't larger
+    fn sub(&mut self, other: &Big) {
+        let mut borrow = 0i64;
+        for (i, word) in self.0.iter_mut().enumerate() {
+            let n = *word as i64 - other.0.get(i).copied().unwrap_or(0) as i64 - borrow;
+            *word = n.rem_euclid(1 << 32) as u32;
+            borrow = (n < 0) as i64;
+        }
+        self.trim();
+    }
+
+    fn trim(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+}
+
+impl PartialOrd for Big {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Big {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.len().cmp(&other.0.len()).then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
@@ -0,0 +1,260 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Translates instructions written in AT&T syntax, like `leaq 8(%rax,%rbx,4), %rdi`, into the Intel syntax the
+//! templates are matched against, so that both syntaxes produce the same instructions
+
+use crate::instructions::Register;
+use crate::parse::lexer::Token;
+use crate::parse::ParseError;
+use std::str::FromStr;
+
+/// Mnemonics AT&T spells differently, apart from the size suffixes
+const ALIASES: [(&str, &str); 12] = [
+    ("cbtw", "cbw"),
+    ("cwtl", "cwde"),
+    ("cltq", "cdqe"),
+    ("cwtd", "cwd"),
+    ("cltd", "cdq"),
+    ("cqto", "cqo"),
+    ("movabs", "mov"),
+    ("movsl", "movsd"),
+    ("cmpsl", "cmpsd"),
+    ("lodsl", "lodsd"),
+    ("scasl", "scasd"),
+    ("stosl", "stosd"),
+];
+
+/// Intel syntax tokens, each with the index of the source token it was made from
+pub type Translation = Vec<(Token, usize)>;
+
+fn suffix_size(suffix: char) -> Option<&'static str> {
+    match suffix {
+        'b' => Some("byte"),
+        'w' => Some("word"),
+        'l' => Some("dword"),
+        'q' => Some("qword"),
+        _ => None,
+    }
+}
+
+fn alias(mnemonic: &str) -> String {
+    ALIASES.iter().find(|a| a.0 == mnemonic).map(|a| a.1).unwrap_or(mnemonic).to_string()
+}
+
+/// Finds the Intel mnemonic, and the size of memory operands if the mnemonic has a size suffix
+fn mnemonic(name: &str, known: &dyn Fn(&str) -> bool) -> (String, Option<&'static str>) {
+    let name = name.to_ascii_lowercase();
+
+    // movzbl, movswq, movslq and so on carry both the source and the destination size
+    for (att, intel) in [("movz", "movzx"), ("movs", "movsx")] {
+        if let Some(sizes) = name.strip_prefix(att) {
+            let sizes = sizes.chars().collect::<Vec<char>>();
+            if let [src, dst] = sizes[..] {
+                if let (Some(size), Some(_)) = (suffix_size(src), suffix_size(dst)) {
+                    let intel = if src == 'l' && att == "movs" { "movsxd" } else { intel };
+                    return (intel.to_string(), Some(size));
+                }
+            }
+        }
+    }
+
+    let plain = alias(&name);
+    if known(&plain) {
+        return (plain, None);
+    }
+
+    if let Some(size) = name.chars().last().and_then(suffix_size) {
+        let stem = alias(&name[..name.len() - 1]);
+        if known(&stem) {
+            return (stem, Some(size));
+        }
+    }
+
+    (plain, None)
+}
+
+fn is_branch(mnemonic: &str) -> bool {
+    mnemonic == "call" || mnemonic.starts_with('j') || mnemonic.starts_with("loop")
+}
+
+fn register(token: &Token, at: usize, len: usize) -> Result<Token, (usize, ParseError)> {
+    match token.as_str().strip_prefix('%') {
+        Some(name) if !name.is_empty() => Ok(Token::new(name)),
+        _ => Err((len - at - 1, ParseError::InvalidOperand)),
+    }
+}
+
+/// Translates one operand, which starts at token `at`
+fn operand(
+    op: &[Token],
+    at: usize,
+    len: usize,
+    size: Option<&str>,
+    branch: bool,
+    out: &mut Translation,
+) -> Result<(), (usize, ParseError)> {
+    let err = |i: usize| (len - at - i - 1, ParseError::InvalidOperand);
+    let first = op.first().ok_or((len - at, ParseError::InvalidOperand))?;
+
+    // Immediates
+    if let Some(imm) = first.as_str().strip_prefix('$') {
+        if imm.is_empty() {
+            if op.len() == 1 {
+                return Err(err(0));
+            }
+        } else {
+            out.push((Token::new(imm), at));
+        }
+        out.extend(op.iter().enumerate().skip(1).map(|(i, t)| (t.clone(), at + i)));
+        return Ok(());
+    }
+
+    // Indirect branch targets
+    let (op, at, indirect) = if first == "*" { (&op[1..], at + 1, true) } else { (op, at, false) };
+    let err = |i: usize| (len - at - i - 1, ParseError::InvalidOperand);
+
+    if op.len() == 1 && op[0].as_str().starts_with('%') {
+        out.push((register(&op[0], at, len)?, at));
+        return Ok(());
+    }
+
+    // Everything else is memory, [%seg:]disp(base, index, scale)
+    let mut rest = 0;
+    let mut segment = None;
+    if op.len() > 2 && op[0].as_str().starts_with('%') && op[1] == ":" {
+        segment = Some(register(&op[0], at, len)?);
+        rest = 2;
+    }
+
+    let open = if op.last().map(|t| t == ")").unwrap_or(false) {
+        let open = op.iter().rposition(|t| t == "(").ok_or(err(op.len() - 1))?;
+        if open < rest {
+            return Err(err(open));
+        }
+        Some(open)
+    } else {
+        None
+    };
+
+    // The Intel parser would take a bare register name for a register, AT&T only has symbols there
+    let disp = &op[rest..open.unwrap_or(op.len())];
+    if let Some(i) = disp.iter().position(|t| t.as_str().starts_with('%') || Register::from_str(t.as_str()).is_ok()) {
+        return Err(err(rest + i));
+    }
+
+    // A plain symbol is the target of a direct branch
+    if branch && !indirect && open.is_none() && segment.is_none() {
+        out.extend(disp.iter().enumerate().map(|(i, t)| (t.clone(), at + rest + i)));
+        return Ok(());
+    }
+
+    if disp.is_empty() && open.is_none() {
+        return Err(err(0));
+    }
+
+    if let Some(size) = size {
+        out.push((Token::new(size), at));
+        out.push((Token::new("ptr"), at));
+    }
+    if let Some(segment) = segment {
+        out.push((segment, at));
+        out.push((Token::new(":"), at + 1));
+    }
+
+    if let Some(open) = open {
+        out.extend(disp.iter().enumerate().map(|(i, t)| (t.clone(), at + rest + i)));
+        out.push((Token::new("["), at + open));
+
+        let inner = &op[open + 1..op.len() - 1];
+        let parts = inner.split(|t| t == ",").collect::<Vec<&[Token]>>();
+        if parts.len() > 3 || parts.iter().skip(1).any(|p| p.len() != 1) || parts[0].len() > 1 {
+            return Err(err(open));
+        }
+
+        let mut pos = open + 1;
+        if let [base] = parts[0] {
+            out.push((register(base, at + pos, len)?, at + pos));
+            pos += 1;
+        }
+        if let Some([index]) = parts.get(1) {
+            if !parts[0].is_empty() {
+                out.push((Token::new("+"), at + pos + 1));
+            }
+            out.push((register(index, at + pos + 1, len)?, at + pos + 1));
+            if let Some([scale]) = parts.get(2) {
+                out.push((Token::new("*"), at + pos + 3));
+                out.push((scale.clone(), at + pos + 3));
+            }
+        } else if parts[0].is_empty() {
+            return Err(err(open));
+        }
+
+        out.push((Token::new("]"), at + op.len() - 1));
+    } else {
+        out.push((Token::new("["), at + rest));
+        out.extend(disp.iter().enumerate().map(|(i, t)| (t.clone(), at + rest + i)));
+        out.push((Token::new("]"), at + op.len() - 1));
+    }
+
+    Ok(())
+}
+
+/// Translates a line of AT&T tokens into Intel tokens. `known` tells which mnemonics exist, so size suffixes can be
+/// told apart from mnemonics that happen to end in b, w, l or q.
+pub fn translate(tokens: &[Token], known: &dyn Fn(&str) -> bool) -> Result<Translation, (usize, ParseError)> {
+    let len = tokens.len();
+    let (name, size) = mnemonic(tokens[0].as_str(), known);
+    let branch = is_branch(&name);
+
+    // Split the operands at commas outside of parentheses
+    let mut operands = Vec::new();
+    let mut start = 1;
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(1) {
+        match token.as_str() {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            "," if depth == 0 => {
+                operands.push((start, i));
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < len || !operands.is_empty() {
+        operands.push((start, len));
+    }
+
+    let mut translated = Vec::new();
+    for (start, end) in operands {
+        let mut out = Vec::new();
+        operand(&tokens[start..end], start, len, size, branch, &mut out)?;
+        translated.push(out);
+    }
+
+    // Shifts and rotates by one can leave out the count
+    if translated.len() == 1 && ["rol", "ror", "rcl", "rcr", "shl", "sal", "shr", "sar"].contains(&name.as_str()) {
+        let at = translated[0].first().map(|t| t.1).unwrap_or(0);
+        translated.insert(0, vec![(Token::new("1"), at)]);
+    }
+
+    // AT&T puts the destination last, except for enter's two immediates
+    if name != "enter" {
+        translated.reverse();
+    }
+
+    let mut res = vec![(Token::new(&name), 0)];
+    for (i, op) in translated.into_iter().enumerate() {
+        if i != 0 {
+            let at = op.first().map(|t| t.1).unwrap_or(0);
+            res.push((Token::new(","), at));
+        }
+        res.extend(op);
+    }
+
+    Ok(res)
+}
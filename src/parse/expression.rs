@@ -0,0 +1,197 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Expressions in operands and arguments of directives, like `.space 2 * (8 + 1)` or `call foo+16`. Operators bind
+//! like in GNU as, which differs from C: `| & ^ !` bind tighter than `+ -`, and comparisons are as loose as those
+
+use crate::parse::helpers::{parse_integer, Specifier};
+use crate::parse::lexer::{Token, OPERATORS};
+use crate::parse::ParseError;
+
+type Error = (usize, ParseError);
+
+/// Binary operators from the loosest to the tightest binding ones
+const LEVELS: [&[&str]; 4] = [
+    &["||", "&&"],
+    &["+", "-", "==", "!=", "<>", "<", ">", "<=", ">="],
+    &["|", "&", "^", "!"],
+    &["*", "/", "%", "<<", ">>"],
+];
+
+/// The value of an expression, which can depend on symbols that are only known when assembling or linking. It's a
+/// constant plus a symbol and minus another one, like `foo+16` or `end - start`, where `.` is the current location
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Value {
+    pub symbol: Option<String>,
+    /// The relocation specifier of `symbol`, like the `@PLT` in `puts@PLT+4`
+    pub specifier: Option<Specifier>,
+    pub minus: Option<String>,
+    pub addend: i64,
+}
+
+impl Value {
+    pub fn constant(addend: i64) -> Self {
+        Value { addend, ..Value::default() }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.symbol.is_none() && self.minus.is_none()
+    }
+}
+
+/// Evaluates a constant expression that makes up all of `tokens`. `after` is the number of tokens that follow them on
+/// the line, so errors point at the right token
+pub fn evaluate(tokens: &[Token], after: usize) -> Result<i64, Error> {
+    let value = value(tokens, after)?;
+    if !value.is_constant() {
+        return Err((tokens.len() - 1 + after, ParseError::InvalidOperand));
+    }
+    Ok(value.addend)
+}
+
+/// Evaluates an expression that makes up all of `tokens`, which may depend on symbols
+pub fn value(tokens: &[Token], after: usize) -> Result<Value, Error> {
+    let mut i = 0;
+    let value = binary(tokens, &mut i, 0).map_err(|(n, e)| (n + after, e))?;
+    if i != tokens.len() {
+        return Err((tokens.len() - i - 1 + after, ParseError::InvalidOperand));
+    }
+    Ok(value)
+}
+
+fn binary(tokens: &[Token], i: &mut usize, level: usize) -> Result<Value, Error> {
+    if level == LEVELS.len() {
+        return unary(tokens, i);
+    }
+
+    let mut value = binary(tokens, i, level + 1)?;
+    while let Some(op) = tokens.get(*i).filter(|t| !t.is_quoted() && LEVELS[level].contains(&t.as_str())) {
+        let at = *i;
+        *i += 1;
+        let rhs = binary(tokens, i, level + 1)?;
+        value = apply(op.as_str(), value, rhs).ok_or((tokens.len() - at - 1, ParseError::InvalidOperand))?;
+    }
+    Ok(value)
+}
+
+/// Adds or subtracts two values. A symbol that is added and subtracted cancels out, at most one of each can remain
+fn add(a: Value, b: Value, subtract: bool) -> Option<Value> {
+    let (b_symbol, b_minus, b_specifier) = if subtract {
+        // Only the added symbol can have a specifier
+        if b.specifier.is_some() {
+            return None;
+        }
+        (b.minus, b.symbol, None)
+    } else {
+        (b.symbol, b.minus, b.specifier)
+    };
+
+    let mut plus = a.symbol.into_iter().chain(b_symbol).collect::<Vec<String>>();
+    let mut minus = a.minus.into_iter().chain(b_minus).collect::<Vec<String>>();
+    while let Some(j) = plus.iter().position(|s| minus.contains(s)) {
+        let symbol = plus.remove(j);
+        minus.retain(|s| *s != symbol);
+    }
+    if plus.len() > 1 || minus.len() > 1 {
+        return None;
+    }
+
+    let specifier = a.specifier.or(b_specifier);
+    if specifier.is_some() && plus.is_empty() {
+        return None;
+    }
+
+    let addend = if subtract { a.addend.wrapping_sub(b.addend) } else { a.addend.wrapping_add(b.addend) };
+    Some(Value { symbol: plus.pop(), specifier, minus: minus.pop(), addend })
+}
+
+/// Applies a binary operator, which only works on constants except for `+` and `-`
+fn apply(op: &str, a: Value, b: Value) -> Option<Value> {
+    match op {
+        "+" => return add(a, b, false),
+        "-" => return add(a, b, true),
+        _ if !a.is_constant() || !b.is_constant() => return None,
+        _ => {}
+    }
+
+    // Like in GNU as, comparisons are -1 if they're true
+    let truth = |condition: bool| -(condition as i64);
+    let (a, b) = (a.addend, b.addend);
+    let value = match op {
+        "*" => a.wrapping_mul(b),
+        "/" => a.checked_div(b)?,
+        "%" => a.checked_rem(b)?,
+        "<<" => u32::try_from(b).ok().and_then(|b| a.checked_shl(b)).unwrap_or(0),
+        ">>" => u32::try_from(b).ok().and_then(|b| (a as u64).checked_shr(b)).unwrap_or(0) as i64,
+        "|" => a | b,
+        "&" => a & b,
+        "^" => a ^ b,
+        // Or not
+        "!" => a | !b,
+        "==" => truth(a == b),
+        "!=" | "<>" => truth(a != b),
+        "<" => truth(a < b),
+        ">" => truth(a > b),
+        "<=" => truth(a <= b),
+        ">=" => truth(a >= b),
+        "&&" => (a != 0 && b != 0) as i64,
+        _ => (a != 0 || b != 0) as i64,
+    };
+    Some(Value::constant(value))
+}
+
+/// Integers can be written unsigned, like `0xffffffffffffffff`, which wraps around to -1
+fn integer(s: &str) -> Option<i64> {
+    parse_integer(s).or_else(|| {
+        match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => u64::from_str_radix(hex, 16).ok(),
+            None => s.parse::<u64>().ok(),
+        }
+        .map(|n| n as i64)
+    })
+}
+
+fn unary(tokens: &[Token], i: &mut usize) -> Result<Value, Error> {
+    let at = *i;
+    let token = tokens.get(at).ok_or((0, ParseError::UnexpectedLB))?;
+    let err = (tokens.len() - at - 1, ParseError::InvalidOperand);
+    *i += 1;
+
+    let constant = |value: Value| if value.is_constant() { Ok(value.addend) } else { Err(err.clone()) };
+    match token.as_str() {
+        _ if token.is_quoted() => {}
+        "-" => {
+            let value = unary(tokens, i)?;
+            if value.specifier.is_some() {
+                return Err(err);
+            }
+            // The symbols swap places, `-(end - start)` is `start - end`
+            let addend = value.addend.wrapping_neg();
+            return Ok(Value { symbol: value.minus, specifier: None, minus: value.symbol, addend });
+        }
+        "+" => return unary(tokens, i),
+        "~" => return Ok(Value::constant(!constant(unary(tokens, i)?)?)),
+        "!" => return Ok(Value::constant((constant(unary(tokens, i)?)? == 0) as i64)),
+        "(" => {
+            let value = binary(tokens, i, 0)?;
+            if tokens.get(*i).is_some_and(|t| t == ")") {
+                *i += 1;
+                return Ok(value);
+            }
+            return Err((tokens.len().saturating_sub(*i + 1), ParseError::InvalidOperand));
+        }
+        // The current location
+        "." | "$" => return Ok(Value { symbol: Some(".".to_string()), ..Value::default() }),
+        number if number.starts_with(|c: char| c.is_ascii_digit()) => {
+            return integer(number).map(Value::constant).ok_or(err);
+        }
+        operator if OPERATORS.contains(&operator) => return Err(err),
+        _ => {}
+    }
+
+    let (symbol, specifier) = Specifier::split(token).ok_or(err)?;
+    Ok(Value { symbol: Some(symbol), specifier, ..Value::default() })
+}
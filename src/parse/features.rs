@@ -0,0 +1,70 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashSet;
+
+/// The CPUID features added by each x86-64 micro-architecture level, as defined by the System V psABI
+const LEVELS: [(&str, &[&str]); 4] = [
+    ("x86-64", &["CMOV", "CX8", "FPU", "FXSR", "MMX", "OSFXSR", "SCE", "SSE", "SSE2"]),
+    ("x86-64-v2", &["CMPXCHG16B", "LAHF-SAHF", "POPCNT", "SSE3", "SSE4_1", "SSE4_2", "SSSE3"]),
+    ("x86-64-v3", &["AVX", "AVX2", "BMI1", "BMI2", "F16C", "FMA", "LZCNT", "MOVBE", "OSXSAVE"]),
+    ("x86-64-v4", &["AVX512F", "AVX512BW", "AVX512CD", "AVX512DQ", "AVX512VL"]),
+];
+
+/// Feature names are compared like GAS does, so `.arch .sse4.2` enables `SSE4_2`
+fn normalize(feature: &str) -> String {
+    feature.to_lowercase().replace(['.', '-'], "_")
+}
+
+/// The CPU features instructions may use. Instructions without a feature are always available.
+#[derive(Debug, Clone)]
+pub struct FeatureSet {
+    all: bool,
+    enabled: HashSet<String>,
+    disabled: HashSet<String>,
+}
+
+impl Default for FeatureSet {
+    fn default() -> Self {
+        FeatureSet { all: true, enabled: HashSet::new(), disabled: HashSet::new() }
+    }
+}
+
+impl FeatureSet {
+    /// The features of a micro-architecture level like `x86-64-v2`, or `all` to not restrict instructions
+    pub fn level(name: &str) -> Option<Self> {
+        if name == "all" {
+            return Some(FeatureSet::default());
+        }
+
+        let index = LEVELS.iter().position(|(level, _)| *level == name)?;
+        let enabled = LEVELS[..=index].iter().flat_map(|(_, features)| features.iter()).map(|f| normalize(f)).collect();
+
+        Some(FeatureSet { all: false, enabled, disabled: HashSet::new() })
+    }
+
+    /// Applies an `.arch` argument, which is either a level or `.feature`/`.nofeature` to toggle a single feature
+    pub fn apply(&mut self, arch: &str) -> Result<(), String> {
+        if let Some(feature) = arch.strip_prefix('.') {
+            if let Some(feature) = feature.strip_prefix("no") {
+                self.enabled.remove(&normalize(feature));
+                self.disabled.insert(normalize(feature));
+            } else {
+                self.disabled.remove(&normalize(feature));
+                self.enabled.insert(normalize(feature));
+            }
+            Ok(())
+        } else {
+            *self = FeatureSet::level(arch).ok_or_else(|| format!("Unknown architecture '{}'", arch))?;
+            Ok(())
+        }
+    }
+
+    pub fn contains(&self, feature: &str) -> bool {
+        let feature = normalize(feature);
+        !self.disabled.contains(&feature) && (self.all || self.enabled.contains(&feature))
+    }
+}
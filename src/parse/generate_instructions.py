@@ -4,9 +4,8 @@
 # License, v. 2.0. If a copy of the MPL was not distributed with this
 # file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-# This script generates a very naive matching algorithm, where, instead of intelligently parsing a line, with several
-# branches, every possible instruction is brute-forced and the first one to not return an error is used.
-# Todo: return the instruction that uses the least bytes
+# This script generates the table of instruction templates the matcher in templates.rs works with, along with an index
+# to find all templates for a mnemonic without going through the whole table.
 
 import re
 import csv
@@ -56,11 +55,29 @@ class Operand:
 
 
 class InstructionTemplate:
-    def __init__(self, opcode, name, op1, op2):
+    def __init__(self, opcode, name, op1, op2, feature, description):
         self.opcode = opcode
         self.name = name.lower()
         self.op1 = Operand(op1)
         self.op2 = Operand(op2)
+        self.feature = feature
+        self.description = description
+
+
+# Legacy prefixes that select an instruction, instead of modifying it
+MANDATORY_PREFIXES = ["66", "F2", "F3"]
+
+# Instructions without 16-bit operands that still need the operand size override, or the other way around
+SIZE_OVERRIDE = ["cbw", "cmpsw", "cwd", "insw", "lodsw", "movsw", "outsw", "scasw", "stosw"]
+NO_SIZE_OVERRIDE = ["fnstsw", "fstsw", "lldt", "lmsw", "ltr", "verr", "verw"]
+
+
+def needs_size_override(name, operands) -> bool:
+    if name in SIZE_OVERRIDE:
+        return True
+    if name in NO_SIZE_OVERRIDE:
+        return False
+    return "O::Specific(\"ax\")" in operands or (operands and operands[0] in ["O::Reg(16)", "O::Rm(16)"])
 
 
 def main():
@@ -71,15 +88,24 @@ def main():
 
     lines = []
     for line in csv_reader:
-        lines.append((line[0], line[1]))
+        lines.append((line[0], line[1], line[2], line[5], line[11]))
     lines.pop(0)
 
     instructions = []
     for line in lines:
         instr = line[0]
         opc = line[1]
+        valid_64 = line[2]
+        feature = line[3]
+        description = line[4]
 
-        if "NP" in opc or "VEX" in opc:
+        # Like the one byte INC and DEC, which are REX prefixes in 64-bit mode
+        if "VEX" in opc or valid_64 in ["Invalid", "N.E."]:
+            continue
+        # NP only forbids the 66, F2 and F3 prefixes, which the templates don't add anyway
+        opc = opc.replace("NP ", "")
+        # A 16-bit push would be picked over PUSH imm32 for small values, but like in GNU as, it takes asking for it
+        if instr == "PUSH imm16":
             continue
 
         unsupported_op_types = ["bnd", "bnd1", "bnd1/m64", "bnd1/m128", "CR0-CR7", "CR8", "DR0-DR7", "k1", "mem", "mm",
@@ -102,80 +128,42 @@ def main():
         elif len(sp) == 3:
             if sp[1] in unsupported_op_types or sp[2] in unsupported_op_types:
                 continue
-            instructions.append(InstructionTemplate(opc, sp[0], sp[1], sp[2]))
+            instructions.append(InstructionTemplate(opc, sp[0], sp[1], sp[2], feature, description))
         elif len(sp) == 2:
             if sp[1] in unsupported_op_types:
                 continue
-            instructions.append(InstructionTemplate(opc, sp[0], sp[1], None))
+            instructions.append(InstructionTemplate(opc, sp[0], sp[1], None, feature, description))
         elif len(sp) == 1:
             if opc in opcode_name_overrides:
                 sp[0] = opcode_name_overrides[opc]
 
-            instructions.append(InstructionTemplate(opc, sp[0], None, None))
-
-    print("""// aron (c) Nikolas Wipper 2022
-
-/* This Source Code Form is subject to the terms of the Mozilla Public
- * License, v. 2.0. If a copy of the MPL was not distributed with this
- * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+            instructions.append(InstructionTemplate(opc, sp[0], None, None, feature, description))
 
-use crate::instructions::{Instruction, Opcode, Mod, Register};
-use crate::parse::lexer::Token;
-use crate::parse::ParseError;
-use crate::parse::helpers::*;""", file=types_header)
-    funcs = []
+    templates = []
 
     for instruction in instructions:
-        print(f"""
-fn matches_{instruction.name}{len(funcs) + 1}(tokens: &Vec<Token>) -> Result<Instruction, (usize, ParseError)> {{
-    let mut iter = tokens.iter();
-    
-    if get_next(&mut iter)? != \"{instruction.name}\" {{ return Err((iter.count(), ParseError::InvalidInstruction)); }}""",
-              file=types_header)
-
-        funcs.append(f"matches_{instruction.name}{len(funcs) + 1}")
-
-        rm = False
-        reg = False
-        imm = []
-        rel = None
+        operands = []
+        imm_parts = 0
 
         for op in [instruction.op1, instruction.op2]:
             if not op.raw:
                 continue
 
-            if op == instruction.op2:
-                print(
-                    "    if get_next(&mut iter)? != \",\" { return Err((iter.count(), ParseError::InvalidOperand)); }",
-                    file=types_header)
-
             if op.is_imm():
-                print(f"    let imm{len(imm) + 1} = is_imm_of_size(&mut iter, {op.get_imm_size()})?;",
-                      file=types_header)
-                imm.append((op, f"imm{len(imm) + 1}"))
+                operands.append(f"O::Imm({op.get_imm_size()})")
             elif op.is_rel():
-                print(f"    let rel = is_rel_of_size(&mut iter, {op.get_rel_size()})?;", file=types_header)
-                rel = op
+                operands.append(f"O::Rel({op.get_rel_size()})")
             elif op.is_specific_operand():
-                print(
-                    f"    if get_next(&mut iter)? != \"{op.raw}\" {{ return Err((iter.count(), ParseError::InvalidOperand)); }}",
-                    file=types_header)
+                operands.append(f"O::Specific(\"{op.raw}\")")
             elif op.is_unspecific_reg():
-                print(f"    let reg = is_reg_of_size(&mut iter, {op.get_reg_size()})?;", file=types_header)
-                reg = True
+                operands.append(f"O::Reg({op.get_reg_size()})")
             elif op.is_unspecific_rm():
-                print(f"    let rm = is_rm_of_size(&mut iter, {op.get_rm_size()})?;", file=types_header)
-                rm = True
+                operands.append(f"O::Rm({op.get_rm_size()})")
             elif op.is_m():
-                print(f"    let rm = is_m_of_size(&mut iter, 0)?;", file=types_header)
-                rm = True
+                operands.append("O::M")
             else:
                 raise RuntimeError("Unsupported op type '" + op.raw + "'")
 
-        print(
-            f"""    if iter.next().is_some() {{ return Err((iter.count(), ParseError::ExtraneousTokenAfterInstruction)); }}""",
-            file=types_header)
-
         # Normalize opcode
         opcode = instruction.opcode \
             .replace("REX +", "REX") \
@@ -184,117 +172,109 @@ fn matches_{instruction.name}{len(funcs) + 1}(tokens: &Vec<Token>) -> Result<Ins
             .replace("/", " /") \
             .replace("  ", " ")
 
-        fill_reg = None
+        digit = "None"
+        prefixes = ["0x66"] if needs_size_override(instruction.name, operands) else []
+        opcode_parts = []
+        imms = []
+        byte_after_imm = False
 
         parts = opcode.split(" ")
+        while len(parts) > 1 and parts[0] in MANDATORY_PREFIXES:
+            prefixes.append("0x" + parts.pop(0))
 
-        for index, p in enumerate(parts):
-            if p.startswith("/"):
-                fill_reg = parts.pop(index)[1:]
-
-        if not reg:
-            if fill_reg and fill_reg != "r":
-                reg = fill_reg
+        for part in parts:
+            if part.startswith("/"):
+                if part != "/r":
+                    digit = f"Some({part[1:]})"
+            elif part.startswith("REX"):
+                w = "true" if part.upper().endswith(".W") else "false"
+                opcode_parts.append(f"P::Rex {{ wide: {w} }}")
+            elif part.startswith("i"):
+                imms.append("P::Imm")
+                imm_parts += 1
+            elif part.startswith("c"):
+                imms.append("P::Rel")
+            elif "+r" in part:
+                opcode_parts.append(f"P::PlusReg(0x{part.split('+r')[0]})")
             else:
-                reg = "0"
-        else:
-            reg = "reg"
-
-        if not rm:
-            rm = "0"
-        else:
-            rm = "rm.0"
+                byte_after_imm |= len(imms) > 0
+                opcode_parts.append(f"P::Byte(0x{part})")
 
-        wrote_rel = False
+        # Immediates are always written after the opcode, so forms like ENTER imm16, 0 (C8 iw 00) can't be encoded.
+        # The general form covers them anyway
+        if byte_after_imm:
+            continue
+        if any(op.startswith("O::Rel") for op in operands) and "P::Rel" not in imms:
+            imms.append("P::Rel")
+        if len(imms) > 2 or imm_parts != len([op for op in operands if op.startswith("O::Imm")]):
+            raise RuntimeError("Immediates don't match opcode of " + instruction.name)
 
-        opcode_array = []
-        imm_count = 1
+        feature = f"Some(\"{instruction.feature}\")" if instruction.feature else "None"
+        description = instruction.description.replace("\\", "\\\\").replace("\"", "\\\"")
+        templates.append((instruction.name, operands, prefixes, opcode_parts + imms, digit, feature, description))
 
-        imms = []
+    write_table(templates, types_header)
 
-        for part in parts:
-            if part.startswith("REX"):
-                w = "true" if part.endswith(".W") else "false"
-                opcode_array.append(f"Opcode::Rex{{wide: {w}}}")
-            elif part.startswith("i"):
-                i = imm.pop(0)
-                imms.append(f"Some({i[1]})")
-                imm_count += 1
-            elif part.startswith("c"):
-                imms.append(f"Some(rel)")
-                wrote_rel = True
 
-                imm_count += 1
-            else:
-                part = part.replace("rb", "reg as u8").replace("rw", "reg as u8").replace("rd", "reg as u8")
+def mnemonic_hash(mnemonic: str) -> int:
+    # FNV-1a, has to stay in sync with `mnemonic_hash` in templates.rs
+    h = 0x811c9dc5
+    for b in mnemonic.encode():
+        h = ((h ^ b) * 0x01000193) & 0xffffffff
+    return h
 
-                opcode_array.append(f"Opcode::Byte(0x{part})")
 
-        instr_mod = "get_mod_from_rm(&rm)" if rm == "rm.0" else "Mod::NoDereference"
-        instr_opcode = "vec![" + (", ".join(opcode_array)) + "]"
-        instr_offset = "rm.2" if rm == "rm.0" else "None"
-        instr_reg = "None"
-        instr_rm = "None"
+def write_table(templates, file):
+    # Templates sharing a mnemonic have to be next to each other, so they can be looked up as one range. The sort is
+    # stable, which keeps the order of the csv for equally long encodings
+    templates = sorted(templates, key=lambda t: t[0])
 
-        if not wrote_rel and rel:
-            imms.append(f"Some(rel)")
+    ranges = {}
+    for i, template in enumerate(templates):
+        start, _ = ranges.get(template[0], (i, i))
+        ranges[template[0]] = (start, i + 1)
 
-        if len(imms) > 2:
-            raise "Too many immediates"
+    slots = 1
+    while slots < len(ranges) * 2:
+        slots *= 2
 
-        while len(imms) < 2:
-            imms.append("None")
+    index = ["None"] * slots
+    for mnemonic, (start, end) in ranges.items():
+        slot = mnemonic_hash(mnemonic) % slots
+        while index[slot] != "None":
+            slot = (slot + 1) % slots
+        index[slot] = f"Some((\"{mnemonic}\", {start}, {end}))"
 
-        instr_imms = ", ".join(imms)
+    print("""// aron (c) Nikolas Wipper 2022
 
-        if rm == "rm.0":
-            # Todo: as i32 is a dirty hack
-            instr_reg = f"Some(Register::try_from({reg} as i32).unwrap())"
-            instr_rm = "Some(rm.0)"
-            instr_offset = "rm.2"
-        elif reg == "reg" and fill_reg:
-            instr_reg = f"Some(Register::try_from({fill_reg}).unwrap())"
-            instr_rm = "Some(reg)"
-            instr_mod = "Mod::NoDereference"
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-        print(f"""
-    Ok(Instruction::new("{instruction.name}".to_string(), {instr_mod}, {instr_opcode}, {instr_offset}, {instr_reg}, {instr_rm}, {instr_imms}))""",
-              file=types_header)
+//! Generated by `generate_instructions.py`, don't edit by hand.
 
-        print("}", file=types_header)
+use crate::parse::templates::{InstructionTemplate, OpcodePart as P, OperandKind as O};
+""", file=file)
 
-    func_list = ", ".join(funcs)
+    print(f"pub static TEMPLATES: [InstructionTemplate; {len(templates)}] = [", file=file)
+    for (mnemonic, operands, prefixes, opcode, digit, feature, description) in templates:
+        print(f"""    InstructionTemplate {{
+        mnemonic: "{mnemonic}",
+        operands: &[{", ".join(operands)}],
+        prefixes: &[{", ".join(prefixes)}],
+        opcode: &[{", ".join(opcode)}],
+        digit: {digit},
+        feature: {feature},
+        description: "{description}",
+    }},""", file=file)
+    print("];", file=file)
 
     print(f"""
-const MATCH_FUNCTIONS: [fn(&Vec<Token>) -> Result<Instruction, (usize, ParseError)>; {len(funcs)}] = [{func_list}];
-
-pub fn matches(tokens: &Vec<Token>) -> Result<Instruction, (usize, ParseError)> {{
-    let mut i: Option<Instruction> = None;
-    let mut l: usize = usize::MAX;
-    let mut err: (usize, ParseError) = (tokens.len() - 1, ParseError::InvalidInstruction);
-    
-    for func in MATCH_FUNCTIONS {{
-        let instr = func(tokens);
-        if let Ok(instr) = instr {{
-            let le = instr.encode().get_bytes().len();
-            if i.is_none() || le < l {{
-                i = Some(instr);
-                l = le;
-            }}
-        }} else {{
-            let instr_err = instr.unwrap_err();
-            if instr_err.0 < err.0 {{
-                err = instr_err;
-            }}
-        }}
-    }}
-    
-    if let Some(i) = i {{
-        Ok(i)
-    }} else {{
-        Err(err)
-    }}
-}}""", file=types_header)
+/// Open addressing hash table over the mnemonics in `TEMPLATES`, with the range of templates for each one
+pub static MNEMONIC_INDEX: [Option<(&str, usize, usize)>; {slots}] = [""", file=file)
+    for slot in index:
+        print(f"    {slot},", file=file)
+    print("];", file=file)
 
 
 if __name__ == "__main__":
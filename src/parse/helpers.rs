@@ -4,10 +4,10 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use crate::instructions::{Mod, Register, Size};
-use crate::parse::lexer::Token;
+use crate::instructions::{Mod, Register, Sib, Size};
+use crate::parse::expression::{self, Value};
+use crate::parse::lexer::{Token, OPERATORS};
 use crate::parse::ParseError;
-use std::mem::size_of;
 use std::slice::Iter;
 use std::str::FromStr;
 
@@ -16,12 +16,72 @@ pub enum Relativity {
     Absolute,
     Relative,
     RipRelative,
+    // RISC-V
+    /// 12-bit pc-relative offset of a conditional branch
+    Branch,
+    /// 20-bit pc-relative offset of a `jal`
+    Jump,
+    /// `auipc` + `jalr` pair of a `call` or `tail`
+    Call,
+    /// `%hi(symbol)`
+    High,
+    /// `%lo(symbol)` in an I-type instruction
+    Low,
+    /// `%lo(symbol)` in an S-type instruction
+    LowStore,
+}
+
+/// A relocation specifier after a symbol, like the `@PLT` in `call puts@PLT`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Specifier {
+    Plt,
+    GotPcRel,
+    Got,
+    TpOff,
+    GotTpOff,
+    TlsGd,
+    TlsLd,
+    DtpOff,
+    /// Mach-O thread local variable
+    Tlvp,
+}
+
+impl Specifier {
+    const NAMES: [(&'static str, Specifier); 9] = [
+        ("plt", Specifier::Plt),
+        ("gotpcrel", Specifier::GotPcRel),
+        ("got", Specifier::Got),
+        ("tpoff", Specifier::TpOff),
+        ("gottpoff", Specifier::GotTpOff),
+        ("tlsgd", Specifier::TlsGd),
+        ("tlsld", Specifier::TlsLd),
+        ("dtpoff", Specifier::DtpOff),
+        ("tlvp", Specifier::Tlvp),
+    ];
+
+    /// Splits the specifier off a symbol, failing on unknown ones. Quoted names are taken as they are, and so are
+    /// NASM's labels starting with `..@`, which it makes for macros and `$`
+    pub fn split(symbol: &Token) -> Option<(String, Option<Specifier>)> {
+        match symbol.as_str().rsplit_once('@') {
+            Some((name, suffix)) if !symbol.is_quoted() && !name.is_empty() && name != ".." => {
+                let specifier = Self::NAMES.iter().find(|n| suffix.eq_ignore_ascii_case(n.0))?.1;
+                Some((name.to_string(), Some(specifier)))
+            }
+            _ => Some((symbol.clone_string(), None)),
+        }
+    }
+
+    /// Whether the specifier is only for thread local variables, like `@tpoff`
+    pub fn is_tls(self) -> bool {
+        !matches!(self, Specifier::Plt | Specifier::GotPcRel | Specifier::Got)
+    }
 }
 
 #[derive(Debug)]
 pub enum ImmediateType {
-    Integer(i32),
-    Reference(String),
+    Integer(i64),
+    /// A value that depends on a symbol, like `foo+16`
+    Reference(Value),
 }
 
 #[derive(Debug)]
@@ -36,12 +96,12 @@ use Relativity::*;
 use crate::instructions::Register::Rip;
 
 impl Immediate {
-    pub fn integer(int: i32, rel: Relativity, size: Size) -> Immediate {
+    pub fn integer(int: i64, rel: Relativity, size: Size) -> Immediate {
         Immediate { rel, typ: Integer(int), size }
     }
 
-    pub fn reference(to: String, rel: Relativity, size: Size) -> Immediate {
-        Immediate { rel, typ: Reference(to), size }
+    pub fn reference(value: Value, rel: Relativity, size: Size) -> Immediate {
+        Immediate { rel, typ: Reference(value), size }
     }
 }
 
@@ -53,8 +113,44 @@ pub fn get_next<'a>(iter: &'a mut Iter<Token>) -> Result<&'a Token, (usize, Pars
     Ok(next.unwrap())
 }
 
-pub fn get_mod_from_rm(rm: &(Register, Mod, Option<Immediate>)) -> Mod {
-    if let Some(off) = &rm.2 {
+/// A register or memory operand, as it's encoded in ModRM
+#[derive(Debug)]
+pub struct Rm {
+    pub reg: Register,
+    pub r#mod: Mod,
+    pub offset: Option<Immediate>,
+    pub sib: Option<Sib>,
+    /// Segment override prefix
+    pub segment: Option<u8>,
+}
+
+impl Rm {
+    pub fn register(reg: Register) -> Self {
+        Rm { reg, r#mod: Mod::NoDereference, offset: None, sib: None, segment: None }
+    }
+}
+
+const SEGMENTS: [(&str, u8); 6] = [("es", 0x26), ("cs", 0x2E), ("ss", 0x36), ("ds", 0x3E), ("fs", 0x64), ("gs", 0x65)];
+
+fn peek_is(iter: &Iter<Token>, s: &str) -> bool {
+    iter.clone().next().map(|t| t.as_str().eq_ignore_ascii_case(s)).unwrap_or(false)
+}
+
+/// Parses a decimal or hexadecimal integer
+pub fn parse_integer(s: &str) -> Option<i64> {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => i64::from_str_radix(hex, 16).ok(),
+        None => s.parse::<i64>().ok(),
+    }
+}
+
+/// spl, bpl, sil and dil can only be addressed with a REX prefix
+pub fn needs_rex(tokens: &[Token]) -> bool {
+    tokens.iter().any(|t| ["spl", "bpl", "sil", "dil"].iter().any(|r| t.as_str().eq_ignore_ascii_case(r)))
+}
+
+pub fn get_mod_from_rm(rm: &Rm) -> Mod {
+    if let Some(off) = &rm.offset {
         if off.rel == RipRelative {
             Mod::NoOffset
         } else {
@@ -66,35 +162,57 @@ pub fn get_mod_from_rm(rm: &(Register, Mod, Option<Immediate>)) -> Mod {
                         Mod::Offset32Bit
                     }
                 }
-                Reference(_) => Mod::Offset32Bit,
+                Reference(..) => Mod::Offset32Bit,
             }
         }
     } else {
-        rm.1
+        rm.r#mod
     }
 }
 
-pub fn is_imm_of_size(iter: &mut Iter<Token>, size: usize) -> Result<Immediate, (usize, ParseError)> {
-    if is_reg_of_size(&mut iter.clone(), 0).is_ok() { return Err((iter.count() - 1, ParseError::InvalidOperand)); }
-
-    let next = get_next(iter)?;
-    let (neg, num) = if next == "-" {
-        let next = get_next(iter)?;
+/// Takes the tokens up to the next comma, which make up the rest of the operand
+fn take_operand<'a>(iter: &mut Iter<'a, Token>) -> &'a [Token] {
+    let rest = iter.as_slice();
+    let len = rest.iter().position(|t| t == ",").unwrap_or(rest.len());
+    if len > 0 {
+        iter.nth(len - 1);
+    }
+    &rest[..len]
+}
 
-        (-1, next.parse::<isize>())
-    } else {
-        let r = next.parse::<isize>();
+/// Evaluates an operand that doesn't name a register
+fn operand_value(iter: &mut Iter<Token>) -> Result<Value, (usize, ParseError)> {
+    let operand = take_operand(iter);
+    let after = iter.clone().count();
+    if let Some(i) = operand.iter().position(|t| !t.is_quoted() && is_register_name(t.as_str())) {
+        return Err((operand.len() - i - 1 + after, ParseError::InvalidOperand));
+    }
 
-        if r.is_err() {
-            return Ok(Immediate::reference(next.clone_string(), Absolute, Size::try_from(size).unwrap()));
+    // GCC writes the address of a symbol as `OFFSET FLAT:symbol`
+    let mut operand = operand;
+    if operand.len() > 1 && operand[0].as_str().eq_ignore_ascii_case("offset") {
+        operand = &operand[1..];
+        if operand.len() > 2 && operand[0].as_str().eq_ignore_ascii_case("flat") && operand[1] == ":" {
+            operand = &operand[2..];
         }
+    }
 
-        (1, r)
-    };
-    let num = num.unwrap();
+    expression::value(operand, after)
+}
 
-    if (size_of::<isize>() * 8 - num.leading_zeros() as usize) <= size - 1 {
-        Ok(Immediate::integer(num as i32 * neg, Absolute, Size::try_from(size).unwrap()))
+/// Parses an immediate of `size` bits. Integers may be written unsigned, like `add al, 200`, which only fits forms the
+/// CPU doesn't sign extend. That depends on the instruction, which checks it with `Instruction::immediates_fit`
+pub fn is_imm_of_size(iter: &mut Iter<Token>, size: usize) -> Result<Immediate, (usize, ParseError)> {
+    let value = operand_value(iter)?;
+    if value.symbol.is_some() {
+        return Ok(Immediate::reference(value, Absolute, Size::try_from(size).unwrap()));
+    } else if value.minus.is_some() {
+        return Err((iter.count(), ParseError::InvalidOperand));
+    }
+
+    let num = value.addend;
+    if (-1i128 << (size - 1)..1i128 << size).contains(&(num as i128)) {
+        Ok(Immediate::integer(num, Absolute, Size::try_from(size).unwrap()))
     } else {
         Err((iter.count(), ParseError::InvalidOperand))
     }
@@ -106,9 +224,12 @@ pub fn is_rel_of_size(iter: &mut Iter<Token>, size: usize) -> Result<Immediate,
         return Err((iter.count(), ParseError::InvalidOperand));
     }
 
-    let next = get_next(iter)?;
+    let value = operand_value(iter)?;
+    if value.symbol.is_none() || value.minus.is_some() {
+        return Err((iter.count(), ParseError::InvalidOperand));
+    }
 
-    return Ok(Immediate::reference(next.clone_string(), Relative, Size::try_from(size).unwrap()));
+    return Ok(Immediate::reference(value, Relative, Size::try_from(size).unwrap()));
 }
 
 const REGS_8_BIT: [&str; 20] = [
@@ -129,8 +250,21 @@ const REGS_64_BIT: [&str; 17] = [
     "rax", "rbx", "rcx", "rdx", "rsi", "rdi", "rsp", "rbp", "r8", "r9", "r10", "r11", "r12", "r13", "r14", "r15", "rip",
 ];
 
+/// Whether a name is a register's, including the segment registers
+pub fn is_register_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    [&REGS_8_BIT[..], &REGS_16_BIT, &REGS_32_BIT, &REGS_64_BIT].iter().any(|regs| regs.contains(&name.as_str()))
+        || SEGMENTS.iter().any(|s| s.0 == name)
+}
+
 pub fn is_reg_of_size(iter: &mut Iter<Token>, size: usize) -> Result<Register, (usize, ParseError)> {
-    let reg = get_next(iter)?;
+    // Quoted names are always symbols
+    let next = get_next(iter)?;
+    if next.is_quoted() {
+        return Err((iter.count(), ParseError::InvalidOperand));
+    }
+
+    let reg = next.as_str().to_ascii_lowercase();
     let works = match size {
         0 => {
             REGS_8_BIT.contains(&reg.as_str())
@@ -145,86 +279,188 @@ pub fn is_reg_of_size(iter: &mut Iter<Token>, size: usize) -> Result<Register, (
         _ => panic!("Invalid size"),
     };
     if works {
-        Ok(Register::from_str(reg.as_str()).unwrap())
+        Ok(Register::from_str(&reg).unwrap())
     } else {
         Err((iter.count(), ParseError::InvalidOperand))
     }
 }
 
-pub fn is_rm_of_size(
-    iter: &mut Iter<Token>,
-    size: usize,
-) -> Result<(Register, Mod, Option<Immediate>), (usize, ParseError)> {
+pub fn is_rm_of_size(iter: &mut Iter<Token>, size: usize) -> Result<Rm, (usize, ParseError)> {
     let reg_res = is_reg_of_size(&mut iter.clone(), size);
     if let Ok(reg_res) = reg_res {
         iter.next();
-        Ok((reg_res, Mod::NoDereference, None))
+        Ok(Rm::register(reg_res))
     } else {
         is_m_of_size(iter, size)
     }
 }
 
-pub fn is_m_of_size(iter: &mut Iter<Token>,
-                    mut size: usize,
-) -> Result<(Register, Mod, Option<Immediate>), (usize, ParseError)> {
-    let next = get_next(iter)?;
-    if next != "[" {
-        if match size {
-            8 => next != "byte",
-            16 => next != "word",
-            32 => next != "dword",
-            64 => next != "qword",
-            0 => !["bytes", "word", "dword", "qword"].contains(&next.as_str()),
-            _ => panic!("Invalid size"),
-        } {
-            return Err((iter.count(), ParseError::InvalidOperand));
+#[derive(Default)]
+struct Address {
+    base: Option<Register>,
+    index: Option<(Register, u8)>,
+    /// The terms that aren't registers with their signs, which add up to the displacement
+    disp: Vec<Token>,
+    /// How many tokens follow each one of `disp`, so errors point at the right one
+    after: Vec<usize>,
+    terms: usize,
+}
+
+/// Adds up the terms of an address, like `rbx + rcx*8 - 16`, until the next bracket or the end of the operand
+fn parse_address(iter: &mut Iter<Token>, address: &mut Address) -> Result<(), (usize, ParseError)> {
+    let mut first = true;
+
+    while let Some(next) = iter.clone().next() {
+        if [",", "[", "]"].contains(&next.as_str()) {
+            break;
         }
 
-        if size == 0 {
-            // fixme: hack
-            size = Size::try_from(next.clone_string()).unwrap() as usize;
+        let sign = match next.as_str() {
+            "+" | "-" => Some(iter.next().unwrap()),
+            _ if !first => return Err((iter.count() - 1, ParseError::InvalidOperand)),
+            _ => None,
+        };
+        first = false;
+        address.terms += 1;
+
+        // A term ends at the next sign that isn't in parentheses or an unary one, like in `8 * -2`
+        let mut term: Vec<(&Token, usize)> = Vec::new();
+        let mut depth = 0usize;
+        while let Some(token) = iter.clone().next() {
+            let value_before =
+                term.last().is_some_and(|(t, _)| t.is_quoted() || !OPERATORS.contains(&t.as_str()) || *t == ")");
+            match token.as_str() {
+                _ if token.is_quoted() => {}
+                "," | "[" | "]" => break,
+                "+" | "-" if depth == 0 && value_before => break,
+                "(" => depth += 1,
+                ")" => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            iter.next();
+            term.push((token, iter.clone().count()));
+        }
+        let &(head, head_after) = term.first().ok_or((iter.clone().count(), ParseError::UnexpectedLB))?;
+
+        if !term.iter().any(|(t, _)| !t.is_quoted() && is_register_name(t.as_str())) {
+            // Terms in front of the brackets and in them add up, like in `8[rbp + 8]`
+            match sign {
+                Some(sign) => address.disp.push(sign.clone()),
+                None if !address.disp.is_empty() => address.disp.push(head.replaced("+")),
+                None => {}
+            }
+            if address.disp.len() > address.after.len() {
+                address.after.push(head_after + 1);
+            }
+            address.disp.extend(term.iter().map(|(t, _)| (*t).clone()));
+            address.after.extend(term.iter().map(|(_, after)| *after));
+            continue;
         }
 
-        if get_next(iter)? != "ptr" {
-            return Err((iter.count(), ParseError::InvalidOperand));
+        let name = head.as_str().to_ascii_lowercase();
+        if !REGS_64_BIT.contains(&name.as_str()) || head.is_quoted() || sign.is_some_and(|s| s == "-") {
+            return Err((head_after, ParseError::InvalidOperand));
         }
-        if get_next(iter)? != "[" {
-            return Err((iter.count(), ParseError::InvalidOperand));
+        let reg = Register::from_str(&name).unwrap();
+
+        match term[..] {
+            [_] if address.base.is_none() => address.base = Some(reg),
+            [_] if address.index.is_none() && reg != Register::Sp && reg != Rip => address.index = Some((reg, 0)),
+            [_, (star, _), (scale, after)] if star == "*" => {
+                let scale = match scale.as_str() {
+                    "1" => 0,
+                    "2" => 1,
+                    "4" => 2,
+                    "8" => 3,
+                    _ => return Err((after, ParseError::InvalidOperand)),
+                };
+                if address.index.is_some() || reg == Register::Sp || reg == Rip {
+                    return Err((after, ParseError::InvalidOperand));
+                }
+                address.index = Some((reg, scale));
+            }
+            _ => return Err((term.last().unwrap().1, ParseError::InvalidOperand)),
         }
-    } else {
-        // rip relative addressing
-        if size == 0 { size = 32 };
-    };
-    let reg_res = is_reg_of_size(iter, 0)?;
+    }
 
-    let rel = if reg_res == Rip {
-        RipRelative
-    } else {
-        Relative
-    };
+    Ok(())
+}
 
-    let next = get_next(iter)?;
+/// Parses a memory operand. Besides `size ptr [base + index*scale + disp]` this accepts GCC's style of putting the
+/// displacement in front of the brackets, like `QWORD PTR -8[rbp]` or `counter[rip]`, and segment overrides.
+pub fn is_m_of_size(iter: &mut Iter<Token>, mut size: usize) -> Result<Rm, (usize, ParseError)> {
+    let mut explicit_size = false;
 
-    let mod_byte: Mod;
-    let mut off: Option<Immediate> = None;
+    if let Some(Ok(mem_size)) = iter.clone().next().map(|t| Size::try_from(t.as_str().to_ascii_lowercase())) {
+        iter.next();
+        if size != 0 && size != 8 << mem_size as usize {
+            return Err((iter.count(), ParseError::InvalidOperand));
+        }
+        size = 8 << mem_size as usize;
+        explicit_size = true;
 
-    if ["+", "-"].contains(&next.as_str()) {
-        let neg = if next.as_str() == "-" { -1 } else { 1 };
-        let off_res = is_imm_of_size(iter, 32)?;
-        off = match off_res.typ {
-            Integer(i) => Some(Immediate::integer(i * neg, rel, Size::try_from(size).unwrap())),
-            Reference(s) => Some(Immediate::reference(s, rel, Size::try_from(size).unwrap())),
-        };
+        if !get_next(iter)?.as_str().eq_ignore_ascii_case("ptr") {
+            return Err((iter.count(), ParseError::InvalidOperand));
+        }
+    } else if size == 0 {
+        size = 32;
+    }
+
+    let mut segment = None;
+    let next = iter.clone().next().filter(|t| !t.is_quoted());
+    if let Some((_, prefix)) = next.and_then(|t| SEGMENTS.iter().find(|s| t.as_str().eq_ignore_ascii_case(s.0))) {
+        if iter.clone().nth(1).map(|t| t == ":").unwrap_or(false) {
+            iter.nth(1);
+            segment = Some(*prefix);
+        }
+    }
+
+    let mut address = Address::default();
+    parse_address(iter, &mut address)?;
 
+    if peek_is(iter, "[") {
+        iter.next();
+        parse_address(iter, &mut address)?;
         if get_next(iter)? != "]" {
             return Err((iter.count(), ParseError::InvalidOperand));
         }
+    } else if !(explicit_size || segment.is_some()) || address.terms == 0 || address.base.is_some() {
+        // Registers are only allowed inside the brackets
+        return Err((iter.count(), ParseError::InvalidOperand));
+    }
 
-        mod_byte = Mod::Offset32Bit;
-    } else if next != "]" {
+    let Address { base, index, disp, after, .. } = address;
+    if base == Some(Rip) && index.is_some() {
         return Err((iter.count(), ParseError::InvalidOperand));
-    } else {
-        mod_byte = Mod::NoOffset;
     }
-    return Ok((reg_res, mod_byte, off));
+
+    // The displacement isn't contiguous in the line, so errors are mapped back to the token they're about
+    let value = if disp.is_empty() {
+        Value::default()
+    } else {
+        expression::value(&disp, 0).map_err(|(n, e)| (after[disp.len().saturating_sub(n + 1)], e))?
+    };
+
+    let rel = if base == Some(Rip) { RipRelative } else { Absolute };
+    let size = Size::try_from(size).unwrap();
+    // Pc-relative displacements are taken from the end of the instruction, which can't be subtracted
+    let offset = if value.symbol.is_some() && (rel == Absolute || value.minus.is_none()) {
+        Some(Immediate::reference(value, rel, size))
+    } else if value.minus.is_some() {
+        return Err((iter.count(), ParseError::InvalidOperand));
+    } else if value.addend == 0 {
+        None
+    } else {
+        let disp = i32::try_from(value.addend).map_err(|_| (iter.clone().count(), ParseError::InvalidOperand))?;
+        Some(Immediate::integer(disp as i64, rel, size))
+    };
+
+    let r#mod = if offset.is_some() { Mod::Offset32Bit } else { Mod::NoOffset };
+    let sib = if index.is_some() || base.is_none() {
+        Some(Sib { base, index: index.map(|i| i.0), scale: index.map(|i| i.1).unwrap_or(0) })
+    } else {
+        None
+    };
+
+    Ok(Rm { reg: if sib.is_some() { Register::Sp } else { base.unwrap() }, r#mod, offset, sib, segment })
 }
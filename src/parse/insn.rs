@@ -0,0 +1,163 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! The `.insn` directive, which encodes instructions that aren't in the instruction table:
+//!
+//! ```asm
+//! .insn 0x0f 0xc7 /7, eax                   # rdseed eax
+//! .insn 0x0f 0xaf, rax, qword ptr [rbx + 8] # imul rax, [rbx + 8]
+//! .insn 0xc7 /0, dword ptr [rbp - 4], 5     # mov dword ptr [rbp - 4], 5
+//! ```
+//!
+//! The opcode bytes are followed by an optional ModRM `/digit`, an r/m operand, a register operand when no digit is
+//! given, and up to two immediates. REX and operand size prefixes are inferred from the operands.
+
+use crate::instructions::{Instruction, Opcode, Operands, Register, Size};
+use crate::parse::helpers::*;
+use crate::parse::lexer::Token;
+use crate::parse::ParseError;
+use std::slice::Iter;
+
+const LEGACY_PREFIXES: [u8; 11] = [0x26, 0x2E, 0x36, 0x3E, 0x64, 0x65, 0x66, 0x67, 0xF0, 0xF2, 0xF3];
+
+fn parse_byte(s: &str) -> Option<Vec<u8>> {
+    if let Some(hex) = s.strip_prefix("0x") {
+        if hex.is_empty() || hex.len() > 6 {
+            return None;
+        }
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        // Wider numbers are split into multiple opcode bytes, big endian, like GAS does
+        let len = hex.len().div_ceil(2);
+        Some(value.to_be_bytes()[4 - len..].to_vec())
+    } else {
+        s.parse::<u8>().ok().map(|b| vec![b])
+    }
+}
+
+fn parse_digit(s: &str) -> Option<Register> {
+    let digit = s.strip_prefix('/')?.parse::<i32>().ok().filter(|d| *d < 8)?;
+    Register::try_from(digit).ok()
+}
+
+fn reg_size(iter: &Iter<Token>) -> Option<usize> {
+    [8, 16, 32, 64].into_iter().find(|size| is_reg_of_size(&mut iter.clone(), *size).is_ok())
+}
+
+fn mem_size(iter: &Iter<Token>) -> Option<usize> {
+    let mut peek = iter.clone();
+    let size = Size::try_from(peek.next()?.clone_string()).ok()?;
+    if peek.next()? == "ptr" {
+        Some(8 << size as usize)
+    } else {
+        None
+    }
+}
+
+pub fn parse_insn(tokens: &[Token]) -> Result<Instruction, (usize, ParseError)> {
+    let mut iter = tokens.iter();
+    get_next(&mut iter)?;
+
+    let mut bytes = Vec::new();
+    let mut digit = None;
+
+    while iter.clone().next().map(|t| t != ",").unwrap_or(false) {
+        let next = get_next(&mut iter)?;
+        if digit.is_some() {
+            return Err((iter.count(), ParseError::InvalidOperand));
+        }
+
+        // The lexer splits the digit off, since / is an operator
+        if next == "/" {
+            let slash = format!("/{}", get_next(&mut iter)?.as_str());
+            digit = Some(parse_digit(&slash).ok_or_else(|| (iter.clone().count(), ParseError::InvalidOperand))?);
+        } else {
+            bytes.extend(parse_byte(next.as_str()).ok_or_else(|| (iter.clone().count(), ParseError::InvalidOperand))?);
+        }
+    }
+
+    if bytes.is_empty() {
+        return Err((iter.count(), ParseError::UnexpectedLB));
+    }
+
+    let mut size = None;
+    let mut registers = Vec::new();
+    let mut memory = None;
+    let mut imms = Vec::new();
+
+    while iter.next().is_some() {
+        if let Some(reg) = reg_size(&iter) {
+            registers.push((is_reg_of_size(&mut iter, reg)?, iter.len()));
+            size = size.or(Some(reg));
+        } else if iter.clone().next().map(|t| t == "[").unwrap_or(false) || mem_size(&iter).is_some() {
+            if memory.is_some() {
+                return Err((iter.len() - 1, ParseError::InvalidOperand));
+            }
+            size = mem_size(&iter).or(size);
+            memory = Some(is_m_of_size(&mut iter, 0)?);
+        } else {
+            let mut imm_size = size.unwrap_or(32).min(32);
+            if let Ok(explicit) = Size::try_from(iter.clone().next().unwrap().clone_string()) {
+                iter.next();
+                imm_size = 8 << explicit as usize;
+            }
+            imms.push(is_imm_of_size(&mut iter, imm_size)?);
+        }
+
+        if iter.clone().next().map(|t| t != ",").unwrap_or(false) {
+            return Err((iter.len() - 1, ParseError::ExtraneousTokenAfterInstruction));
+        }
+    }
+
+    if registers.len() + memory.is_some() as usize > 2 || imms.len() > 2 {
+        return Err((0, ParseError::InvalidOperand));
+    }
+
+    // A memory operand is always the r/m operand, otherwise the first register is
+    let rm = if let Some(memory) = memory {
+        Some(memory)
+    } else if !registers.is_empty() {
+        Some(Rm::register(registers.remove(0).0))
+    } else {
+        None
+    };
+
+    let reg = match (digit, registers.pop()) {
+        (Some(_), Some((_, at))) => return Err((at, ParseError::InvalidOperand)),
+        (None, None) if rm.is_some() => return Err((0, ParseError::InvalidOperand)),
+        (Some(_), None) if rm.is_none() => return Err((0, ParseError::UnexpectedLB)),
+        (digit, reg) => digit.or(reg.map(|r| r.0)),
+    };
+
+    // REX is inferred for extended registers, only REX.W has to be asked for
+    let wide = size == Some(64);
+
+    let mut opcode = Vec::new();
+    let prefixes = bytes.iter().take_while(|b| LEGACY_PREFIXES.contains(b)).count();
+    if size == Some(16) && !bytes[..prefixes].contains(&0x66) {
+        opcode.push(Opcode::Prefix(0x66));
+    }
+    for (i, byte) in bytes.into_iter().enumerate() {
+        if i == prefixes && wide {
+            opcode.push(Opcode::Rex { wide });
+        }
+        opcode.push(if i < prefixes { Opcode::Prefix(byte) } else { Opcode::Byte(byte) });
+    }
+
+    let mut imms = imms.into_iter();
+    let imms = Operands::immediates(imms.next(), imms.next());
+    let instr = match rm {
+        Some(rm) => {
+            let operands = Operands { r#mod: get_mod_from_rm(&rm), offset: rm.offset, reg, rm: Some(rm.reg), ..imms };
+            Instruction::new(".insn".to_string(), opcode, operands).with_memory(rm.sib, rm.segment)
+        }
+        None => Instruction::new(".insn".to_string(), opcode, imms),
+    };
+
+    if !instr.immediates_fit() {
+        return Err((0, ParseError::InvalidOperand));
+    }
+    Ok(instr)
+}
@@ -24,15 +24,22 @@ impl LexPosition {
 #[derive(Clone, Debug)]
 pub struct Token {
     raw: String,
-    pos: LexPosition
+    pos: LexPosition,
+    /// Whether the token was a quoted string, which the raw text doesn't tell anymore
+    quoted: bool,
 }
 
-pub(crate) const OPERATORS: &[&str] = &[":", ",", "+", "-", "[", "]"];
+pub(crate) const OPERATORS: &[&str] = &[
+    ":", ",", "+", "-", "*", "[", "]", "(", ")", "=", "/", "<<", ">>", "<", ">", "<=", ">=", "==", "!=", "<>", "&", "|",
+    "^", "~", "!", "&&", "||", "%",
+];
 
 pub struct Lexer {
     code: String,
     c: Result<char, ParseError>,
     pos: LexPosition,
+    /// Whether a backslash escapes the quote that would end a string, which it doesn't in NASM
+    escapes: bool,
 }
 
 fn starts_operator(c: &str) -> bool {
@@ -48,10 +55,21 @@ impl Token {
     pub fn new(s: &str) -> Self {
         Token {
             raw: s.to_string(),
-            pos: LexPosition { line: 0, char: 0, pos: 0 }
+            pos: LexPosition { line: 0, char: 0, pos: 0 },
+            quoted: false,
         }
     }
 
+    /// A token that stands for a symbol, even if its name is a register's, like `"rax"`
+    pub fn quoted(s: &str) -> Self {
+        Token { quoted: true, ..Token::new(s) }
+    }
+
+    /// A token with other text in the place of this one, so errors about it still point into the source
+    pub fn replaced(&self, s: &str) -> Self {
+        Token { raw: s.to_string(), pos: self.pos, quoted: false }
+    }
+
     pub fn as_str(&self) -> &str {
         self.raw.as_str()
     }
@@ -65,6 +83,10 @@ impl Token {
         self.raw.clone()
     }
 
+    pub fn is_quoted(&self) -> bool {
+        self.quoted
+    }
+
     pub fn get_pos(&self) -> &LexPosition {
         &self.pos
     }
@@ -118,7 +140,11 @@ impl Lexer {
         } else {
             Ok(char.unwrap())
         };
-        Lexer { code, c, pos: LexPosition::new() }
+        Lexer { code, c, pos: LexPosition::new(), escapes: true }
+    }
+
+    pub fn without_escapes(self) -> Lexer {
+        Lexer { escapes: false, ..self }
     }
 
     fn read_char(&mut self) -> &Result<char, ParseError> {
@@ -160,8 +186,15 @@ impl Lexer {
         *self.get_last_char().unwrap()
     }
 
+    /// Whether the last character ends a value, so a `%` after it is the remainder, like in `10%3`
+    fn follows_value(&self) -> bool {
+        let last = self.code[..self.pos.pos].chars().next_back();
+        last.is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | ')' | '$'))
+    }
+
     pub fn read(&mut self) -> Result<Token, ParseError> {
         let mut token = String::new();
+        let mut quoted = false;
         const STOPPERS: &str = " \t\n#";
 
         let skippable = |c: char| -> bool { (c.is_whitespace() && c != '\n') || c == '\t' || c == '#' };
@@ -177,22 +210,35 @@ impl Lexer {
         if self.get_char_or('\0') == '\0' {
             return Err(ParseError::UnexpectedLB);
         } else if self.get_char() == '"' || self.get_char() == '\'' {
+            quoted = true;
+            let quote = self.get_char();
+            let mut escaped = false;
             loop {
                 self.read_char();
-                if self.get_char_or('\0') == '\0' || self.get_char_or('\0') == '\n' {
+                let char = self.get_char_or('\0');
+                if char == '\0' || char == '\n' {
                     break;
                 }
-                token.push(self.get_char_or('\0'));
-                if token.chars().last().unwrap() == '\'' || token.chars().last().unwrap() == '"' {
-                    token.pop();
+                if char == quote && !escaped {
+                    // Step over the closing quote, it would start another string otherwise
+                    self.read_char();
                     break;
                 }
+                // Escapes are kept, so the directive can decode them
+                escaped = self.escapes && char == '\\' && !escaped;
+                token.push(char);
             }
         } else {
             let mut op = false;
             loop {
                 let char = self.get_char();
-                if STOPPERS.contains(char) {
+                // Anywhere else `%` starts a name, like `%rax`, NASM's `%define` and `%%label` or RISC-V's `%hi`
+                let prefix = char == '%'
+                    && !op
+                    && if token.is_empty() { !self.follows_value() } else { token.chars().all(|c| c == '%') };
+                if prefix {
+                    token.push(char);
+                } else if STOPPERS.contains(char) {
                     if token.is_empty() {
                         token = char.to_string();
                         self.read_char();
@@ -225,7 +271,8 @@ impl Lexer {
 
         Ok(Token {
             raw: token,
-            pos: self.pos
+            pos: self.pos,
+            quoted,
         })
     }
 
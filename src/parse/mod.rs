@@ -4,13 +4,20 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-mod encodings;
+pub mod att;
+pub mod encodings;
+pub mod expression;
+pub mod features;
 pub mod helpers;
-mod lexer;
+pub mod insn;
+pub mod lexer;
+pub mod nasm;
 pub mod parser;
+pub mod templates;
 mod tests;
 
-use crate::instructions::Instruction;
+use crate::instructions::Encodable;
+use crate::parse::expression::Value;
 use crate::parse::lexer::Token;
 
 #[derive(Debug, Clone)]
@@ -22,6 +29,8 @@ pub enum ParseError {
     InvalidDirective,
     ExtraneousTokenBeforeLabel(Token),
     ExtraneousTokenAfterInstruction,
+    DisabledFeature(String),
+    SymbolRedefined(String),
 }
 
 impl ParseError {
@@ -34,6 +43,8 @@ impl ParseError {
             ParseError::InvalidDirective => 5,
             ParseError::ExtraneousTokenBeforeLabel(_) => 6,
             ParseError::ExtraneousTokenAfterInstruction => 7,
+            ParseError::DisabledFeature(_) => 8,
+            ParseError::SymbolRedefined(_) => 9,
         }
     }
 }
@@ -48,6 +59,8 @@ impl ToString for ParseError {
             ParseError::InvalidDirective => "Invalid directive".to_string(),
             ParseError::ExtraneousTokenBeforeLabel(_) => "Extraneous token before label".to_string(),
             ParseError::ExtraneousTokenAfterInstruction => "Extraneous token after instruction".to_string(),
+            ParseError::DisabledFeature(feature) => format!("Instruction needs {}, which isn't enabled", feature),
+            ParseError::SymbolRedefined(symbol) => format!("Symbol '{}' is already defined", symbol),
         }
     }
 }
@@ -56,15 +69,118 @@ impl ToString for ParseError {
 pub enum Line {
     Directive(Directive),
     Label(String),
-    Instruction(Instruction),
+    Instruction(Box<dyn Encodable>),
 }
 
 #[derive(Debug)]
 pub enum Directive {
-    Asciz(String),
+    /// Pads to a boundary with a fill byte, or with NOPs in code, unless that takes more bytes than the maximum, like
+    /// `.p2align 4, 0x90, 10`
+    Align(Alignment, Option<u8>, Option<usize>),
+    Arch,
     BuildVersion(BuildVersion),
+    /// Raw data, like NASM's `db 'hi', 10` or `.asciz "hi"`
+    Bytes(Vec<u8>),
+    /// A symbol of zeros the linker reserves, which other modules can have too, like `.comm buf, 64, 32`. Ones made
+    /// local with `.local` are reserved in `.bss` like with `.lcomm`, which is how GCC writes zeroed `static`s
+    Common(String, usize, Alignment),
+    /// Values of one size in bytes, like `.long 1, .LBB0_3-.LJTI0_0`
+    Data(usize, Vec<DataValue>),
     Global(String),
-    Section(String),
+    /// Makes a symbol local, like `.local counter`
+    Local(String),
+    /// Moves forward to a location in the section, filling the gap with a byte, like `.org 0x1fe`. The location is a
+    /// constant or relative to `.`, like `.org . + 4`
+    Org(Value, u8),
+    /// Switches to a section, with the flags and type of ELF sections if they're given, like
+    /// `.section .tdata,"awT",@progbits`
+    Section(String, Option<SectionAttributes>),
+    /// Defines a symbol as a constant or as another symbol, like `.equ BUFSIZE, 4096`, `SYS_write = 1` or
+    /// `.set alias, main`
+    Set(String, SymbolValue),
+    Size(String, SymbolSize),
+    /// A number of zero bytes, like `.zero 64` or NASM's `resb 64`
+    Space(usize),
+    Syntax,
+    Type(String, SymbolType),
+    Unknown,
+    /// Reserves space for a local symbol in an uninitialized section, `.bss` unless given, like `.lcomm buf, 64` or
+    /// `.zerofill __DATA,__bss,_buf,64,4`
+    Zerofill { section: Option<String>, symbol: Option<(String, usize, Alignment)> },
+}
+
+/// The assembly syntax instructions are written in, selected by `--syntax` or `.att_syntax`/`.intel_syntax`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Syntax {
+    #[default]
+    Intel,
+    Att,
+    /// NASM's dialect of Intel syntax, which has its own directives and is parsed by `nasm::parse_lines`
+    Nasm,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataValue {
+    Integer(i128),
+    /// A value that depends on symbols, like `table+8` or `.LBB0_3-.LJTI0_0`, where `.` is the location of the value
+    /// itself
+    Reference(Value),
+}
+
+/// The boundary of an alignment directive
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Alignment {
+    /// `.balign`
+    Bytes(u64),
+    /// `.p2align`
+    PowerOfTwo(u64),
+    /// `.align`, which is in bytes or a power of two depending on the target and object file format
+    Either(u64),
+}
+
+impl Alignment {
+    /// The boundary in bytes, or `None` if it isn't a power of two
+    pub fn bytes(self, align_in_bytes: bool) -> Option<u64> {
+        match self {
+            // Like `.balign 0`, which doesn't align at all
+            Alignment::Bytes(0) => Some(1),
+            Alignment::Bytes(bytes) => bytes.is_power_of_two().then_some(bytes),
+            Alignment::Either(bytes) if align_in_bytes => Alignment::Bytes(bytes).bytes(true),
+            Alignment::PowerOfTwo(power) | Alignment::Either(power) => 1u64.checked_shl(power.try_into().ok()?),
+        }
+    }
+}
+
+/// The flags, type and entry size of an ELF section, like the `"aMS",@progbits,1` of
+/// `.section .rodata.str1.1,"aMS",@progbits,1`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SectionAttributes {
+    /// `SHF_*` flags
+    pub flags: u64,
+    /// The `SHT_*` type, if it's given
+    pub typ: Option<u32>,
+    /// The size of the entries of sections the linker merges
+    pub entsize: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolValue {
+    Constant(i64),
+    /// Another symbol, which may be defined later or in another module
+    Symbol(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolSize {
+    Constant(u64),
+    /// Distance between two labels in the same section, like `.Lfunc_end0-main`
+    Difference(String, String),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SymbolType {
+    Function,
+    Object,
     Unknown,
 }
 
@@ -0,0 +1,805 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Parses source written for NASM, which has its own directives, data definitions, macros and spelling of memory
+//! operands, into the same lines as the GNU syntaxes. Instructions are rewritten into Intel syntax, like the AT&T
+//! front end does, and matched by the target.
+
+use crate::parse::expression;
+use crate::parse::lexer::Token;
+use crate::parse::parser::{lex_lines, report_error};
+use crate::parse::{DataValue, Directive, Line, ParseError, SymbolType, SymbolValue};
+use crate::target::Target;
+use std::collections::HashMap;
+
+type Error = (usize, ParseError);
+
+const SIZES: [&str; 8] = ["byte", "word", "dword", "qword", "tword", "oword", "yword", "zword"];
+const SEGMENTS: [&str; 6] = ["cs", "ds", "es", "fs", "gs", "ss"];
+
+/// The special symbols after `wrt`, with the relocation specifier the Intel parser knows them by
+const WRT: [(&str, &str); 6] = [
+    ("..plt", "@PLT"),
+    ("..got", "@GOT"),
+    ("..gotpcrel", "@GOTPCREL"),
+    ("..gottpoff", "@GOTTPOFF"),
+    ("..tlsie", "@GOTTPOFF"),
+    ("..sym", ""),
+];
+
+/// Unit size of the data and reservation keywords
+fn data_size(keyword: &str) -> Option<usize> {
+    match keyword {
+        "db" | "resb" => Some(1),
+        "dw" | "resw" => Some(2),
+        "dd" | "resd" => Some(4),
+        "dq" | "resq" => Some(8),
+        _ => None,
+    }
+}
+
+/// Keywords that make the word before them a label, even without a colon
+fn follows_label(keyword: &str) -> bool {
+    data_size(keyword).is_some() || keyword == "times" || keyword == "equ"
+}
+
+/// Turns `symbol wrt ..plt` into `symbol@PLT`, returning the source index of a `wrt` that can't be translated
+fn specifiers(translation: &mut Vec<(Token, usize)>) -> Result<(), usize> {
+    while let Some(i) = translation.iter().position(|t| t.0.as_str().eq_ignore_ascii_case("wrt")) {
+        let special = translation
+            .get(i + 1)
+            .filter(|_| i > 0)
+            .and_then(|t| WRT.iter().find(|w| t.0.as_str().eq_ignore_ascii_case(w.0)))
+            .ok_or(translation[i].1)?;
+
+        let (symbol, at) = &translation[i - 1];
+        translation[i - 1] = (Token::new(&format!("{}{}", symbol.as_str(), special.1)), *at);
+        translation.drain(i..i + 2);
+    }
+    Ok(())
+}
+
+/// Parses NASM's integer formats: 0x1f, 1fh, 0b101, 101b, 0o17, 17q and so on, with optional underscores
+fn parse_number(s: &str) -> Option<i64> {
+    let s = s.replace('_', "").to_ascii_lowercase();
+    if !s.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let prefixed = |prefixes: [&str; 2]| prefixes.iter().find_map(|p| s.strip_prefix(p));
+    let (digits, radix) = if let Some(digits) = s.strip_suffix('h') {
+        (digits, 16)
+    } else if let Some(digits) = prefixed(["0x", "0h"]) {
+        (digits, 16)
+    } else if let Some(digits) = prefixed(["0b", "0y"]) {
+        (digits, 2)
+    } else if let Some(digits) = prefixed(["0o", "0q"]) {
+        (digits, 8)
+    } else if let Some(digits) = prefixed(["0d", "0t"]) {
+        (digits, 10)
+    } else if let Some(digits) = s.strip_suffix(['b', 'y']) {
+        (digits, 2)
+    } else if let Some(digits) = s.strip_suffix(['o', 'q']) {
+        (digits, 8)
+    } else if let Some(digits) = s.strip_suffix(['d', 't']) {
+        (digits, 10)
+    } else {
+        (s.as_str(), 10)
+    };
+
+    u64::from_str_radix(digits, radix).ok().map(|n| n as i64)
+}
+
+/// Replaces comments, which start with ';' in NASM, with spaces, so token positions stay the same
+fn strip_comments(code: &str) -> String {
+    let mut res = String::with_capacity(code.len());
+    let mut quote = None;
+    let mut comment = false;
+
+    for c in code.chars() {
+        match c {
+            '\n' => {
+                comment = false;
+                quote = None;
+            }
+            _ if comment => {
+                res.push(' ');
+                continue;
+            }
+            '"' | '\'' | '`' if quote.is_none() => quote = Some(c),
+            _ if quote == Some(c) => quote = None,
+            ';' if quote.is_none() => {
+                comment = true;
+                res.push(' ');
+                continue;
+            }
+            _ => {}
+        }
+        res.push(c);
+    }
+
+    res
+}
+
+/// Splits the tokens from `start` to the end at commas outside of brackets or parentheses
+fn split_operands(tokens: &[Token], start: usize) -> Vec<(usize, usize)> {
+    let mut operands = Vec::new();
+    let mut begin = start;
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        match token.as_str() {
+            "[" | "(" => depth += 1,
+            "]" | ")" => depth -= 1,
+            "," if depth == 0 => {
+                operands.push((begin, i));
+                begin = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if begin < tokens.len() || !operands.is_empty() {
+        operands.push((begin, tokens.len()));
+    }
+
+    operands
+}
+
+/// A value NASM can compute while assembling, either a plain number or an offset into a section
+#[derive(Debug, Clone, PartialEq)]
+struct Value {
+    section: Option<String>,
+    offset: i64,
+}
+
+impl Value {
+    fn number(offset: i64) -> Self {
+        Value { section: None, offset }
+    }
+}
+
+struct Macro {
+    params: usize,
+    body: Vec<Vec<Token>>,
+}
+
+struct Nasm<'a> {
+    target: &'a mut dyn Target,
+    lines: Vec<Line>,
+    defines: HashMap<String, Vec<Token>>,
+    macros: HashMap<String, Macro>,
+    /// The macro between `%macro` and `%endmacro`, with its name
+    recording: Option<(Token, Macro)>,
+    constants: HashMap<String, i64>,
+    /// The section and offset of every label, for `$ - msg` and the like
+    labels: HashMap<String, Value>,
+    section: String,
+    offsets: HashMap<String, i64>,
+    /// The last label not starting with a dot, which local labels belong to
+    scope: String,
+    /// Numbers macro expansions and every `$` in an instruction, to give their labels unique names
+    unique: usize,
+}
+
+impl<'a> Nasm<'a> {
+    fn new(target: &'a mut dyn Target) -> Self {
+        Nasm {
+            target,
+            lines: vec![Line::Directive(Directive::Section(".text".to_string(), None))],
+            defines: HashMap::new(),
+            macros: HashMap::new(),
+            recording: None,
+            constants: HashMap::new(),
+            labels: HashMap::new(),
+            section: ".text".to_string(),
+            offsets: HashMap::new(),
+            scope: String::new(),
+            unique: 0,
+        }
+    }
+
+    fn offset(&self) -> i64 {
+        self.offsets.get(&self.section).copied().unwrap_or(0)
+    }
+
+    fn advance(&mut self, size: usize) {
+        *self.offsets.entry(self.section.clone()).or_insert(0) += size as i64;
+    }
+
+    /// Local labels like .loop belong to the last label before them
+    fn qualify(&self, name: &str) -> String {
+        if name.starts_with('.') && !name.starts_with("..") {
+            format!("{}{}", self.scope, name)
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn unique_label(&mut self, name: &str) -> String {
+        self.unique += 1;
+        format!("..@{}.{}", self.unique, name)
+    }
+
+    /// Evaluates the expression starting at `start`, returning its value and the index of the first token after it
+    fn eval(&self, tokens: &[Token], start: usize) -> Result<(Value, usize), Error> {
+        let mut i = start;
+        let value = self.sum(tokens, &mut i)?;
+        Ok((value, i))
+    }
+
+    fn sum(&self, tokens: &[Token], i: &mut usize) -> Result<Value, Error> {
+        let mut value = self.product(tokens, i)?;
+        while let Some(op) = tokens.get(*i).filter(|t| *t == "+" || *t == "-") {
+            let at = *i;
+            *i += 1;
+            let rhs = self.product(tokens, i)?;
+
+            // Offsets into the same section can be subtracted, but nothing can be added to them apart from numbers
+            value = match (op.as_str(), value.section, rhs.section) {
+                ("+", section, None) => Value { section, offset: value.offset.wrapping_add(rhs.offset) },
+                ("+", None, section) => Value { section, offset: value.offset.wrapping_add(rhs.offset) },
+                ("-", section, None) => Value { section, offset: value.offset.wrapping_sub(rhs.offset) },
+                ("-", Some(a), Some(b)) if a == b => Value::number(value.offset - rhs.offset),
+                _ => return Err((tokens.len() - at - 1, ParseError::InvalidOperand)),
+            };
+        }
+        Ok(value)
+    }
+
+    fn product(&self, tokens: &[Token], i: &mut usize) -> Result<Value, Error> {
+        let mut value = self.unary(tokens, i)?;
+        while tokens.get(*i).is_some_and(|t| t == "*") {
+            let at = *i;
+            *i += 1;
+            let rhs = self.unary(tokens, i)?;
+            if value.section.is_some() || rhs.section.is_some() {
+                return Err((tokens.len() - at - 1, ParseError::InvalidOperand));
+            }
+            value = Value::number(value.offset.wrapping_mul(rhs.offset));
+        }
+        Ok(value)
+    }
+
+    fn unary(&self, tokens: &[Token], i: &mut usize) -> Result<Value, Error> {
+        let at = *i;
+        let token = tokens.get(at).ok_or((0, ParseError::UnexpectedLB))?;
+        let err = (tokens.len() - at - 1, ParseError::InvalidOperand);
+        *i += 1;
+
+        if token.is_quoted() {
+            // Character constants are little endian
+            let bytes = token.as_str().as_bytes();
+            if bytes.len() > 8 {
+                return Err(err);
+            }
+            return Ok(Value::number(bytes.iter().rev().fold(0, |n, b| (n << 8) | *b as i64)));
+        }
+
+        match token.as_str() {
+            "-" => {
+                let value = self.unary(tokens, i)?;
+                if value.section.is_some() {
+                    return Err(err);
+                }
+                Ok(Value::number(value.offset.wrapping_neg()))
+            }
+            "+" => self.unary(tokens, i),
+            "(" => {
+                let value = self.sum(tokens, i)?;
+                if tokens.get(*i).is_some_and(|t| t == ")") {
+                    *i += 1;
+                    Ok(value)
+                } else {
+                    Err((tokens.len().saturating_sub(*i + 1), ParseError::InvalidOperand))
+                }
+            }
+            "$" => Ok(Value { section: Some(self.section.clone()), offset: self.offset() }),
+            "$$" => Ok(Value { section: Some(self.section.clone()), offset: 0 }),
+            name => {
+                if let Some(n) = parse_number(name) {
+                    return Ok(Value::number(n));
+                }
+
+                let name = self.qualify(name);
+                if let Some(n) = self.constants.get(&name) {
+                    Ok(Value::number(*n))
+                } else {
+                    // Later symbols are left to the assembler in data, instructions and equ. Todo: the others, like
+                    // times, NASM makes as many passes as it needs for them
+                    self.labels.get(&name).cloned().ok_or(err)
+                }
+            }
+        }
+    }
+
+    /// Evaluates an expression that has to be a plain number and make up the rest of the line
+    fn number(&self, tokens: &[Token], start: usize) -> Result<i64, Error> {
+        let (value, end) = self.eval(tokens, start)?;
+        if end != tokens.len() {
+            return Err((tokens.len() - end - 1, ParseError::InvalidOperand));
+        }
+        if value.section.is_some() {
+            return Err((tokens.len() - start - 1, ParseError::InvalidOperand));
+        }
+        Ok(value.offset)
+    }
+
+    /// Replaces `%define`d names, again and again, since their values can use other definitions
+    fn expand_defines(&self, mut tokens: Vec<Token>) -> Vec<Token> {
+        for _ in 0..32 {
+            if !tokens.iter().any(|t| !t.is_quoted() && self.defines.contains_key(t.as_str())) {
+                break;
+            }
+            tokens = tokens
+                .into_iter()
+                .flat_map(|t| match self.defines.get(t.as_str()) {
+                    Some(value) if !t.is_quoted() => value.clone(),
+                    _ => vec![t],
+                })
+                .collect();
+        }
+        tokens
+    }
+
+    /// Processes one line, reporting errors at the token they were caused by
+    fn process(&mut self, tokens: Vec<Token>) -> Result<(), (Token, ParseError)> {
+        if let Some((_, recording)) = &mut self.recording {
+            if !tokens[0].as_str().eq_ignore_ascii_case("%endmacro") {
+                recording.body.push(tokens);
+                return Ok(());
+            }
+        }
+
+        let tokens = if tokens[0].as_str().starts_with('%') { tokens } else { self.expand_defines(tokens) };
+        self.line(&tokens).map_err(|(i, e)| (tokens[tokens.len().saturating_sub(i + 1)].clone(), e))
+    }
+
+    fn line(&mut self, tokens: &[Token]) -> Result<(), Error> {
+        let first = tokens[0].as_str().to_ascii_lowercase();
+        if first.starts_with('%') {
+            return self.preprocessor(tokens, &first);
+        }
+
+        // Directives can also be written in brackets, like [bits 64]
+        if first == "[" && tokens.last().unwrap() == "]" && tokens.len() > 2 {
+            let inner = &tokens[1..tokens.len() - 1];
+            return self.line(inner).map_err(|(i, e)| (i + 1, e));
+        }
+
+        let second = tokens.get(1).map(|t| t.as_str().to_ascii_lowercase()).unwrap_or_default();
+        let label = if second == ":" {
+            Some(2)
+        } else if follows_label(&second) || (tokens.len() == 1 && !self.is_keyword(&first)) {
+            Some(1)
+        } else {
+            None
+        };
+
+        let Some(start) = label else {
+            return self.statement(tokens, 0);
+        };
+
+        let name = self.qualify(tokens[0].as_str());
+        if tokens.get(start).is_some_and(|t| t.as_str().eq_ignore_ascii_case("equ")) {
+            // Instructions before the definition refer to it as a symbol, which the assembler fills in later. A
+            // single label makes an alias, which can also be defined later
+            let is_label = |t: &Token| {
+                let raw = t.as_str();
+                !t.is_quoted()
+                    && !raw.starts_with('$')
+                    && parse_number(raw).is_none()
+                    && !self.constants.contains_key(&self.qualify(raw))
+            };
+            let value = match &tokens[start + 1..] {
+                [symbol] if is_label(symbol) => SymbolValue::Symbol(self.qualify(symbol.as_str())),
+                _ => {
+                    let value = self.number(tokens, start + 1)?;
+                    self.constants.insert(name.clone(), value);
+                    SymbolValue::Constant(value)
+                }
+            };
+            self.lines.push(Line::Directive(Directive::Set(name, value)));
+            return Ok(());
+        }
+
+        if !tokens[0].as_str().starts_with('.') {
+            self.scope = name.clone();
+        }
+        self.labels.insert(name.clone(), Value { section: Some(self.section.clone()), offset: self.offset() });
+        self.lines.push(Line::Label(name));
+
+        self.statement(tokens, start)
+    }
+
+    /// Whether a line consisting of only this word isn't a label
+    fn is_keyword(&self, word: &str) -> bool {
+        self.macros.contains_key(word) || !self.target.instruction_forms(Some(word)).is_empty()
+    }
+
+    fn preprocessor(&mut self, tokens: &[Token], directive: &str) -> Result<(), Error> {
+        let len = tokens.len();
+        let name = || tokens.get(1).ok_or((0, ParseError::UnexpectedLB));
+
+        match directive {
+            "%define" => {
+                self.defines.insert(name()?.clone_string(), tokens[2..].to_vec());
+            }
+            "%undef" => {
+                self.defines.remove(name()?.as_str());
+            }
+            "%macro" => {
+                // Todo: parameter ranges and defaults, like %macro name 1-3 a, b
+                let params = tokens
+                    .get(2)
+                    .and_then(|t| parse_number(t.as_str()))
+                    .ok_or((len.saturating_sub(3), ParseError::InvalidDirective))?;
+                if len > 3 {
+                    return Err((len - 4, ParseError::InvalidDirective));
+                }
+                let recording = Macro { params: params as usize, body: Vec::new() };
+                self.recording = Some((name()?.clone(), recording));
+            }
+            "%endmacro" => {
+                let (name, recording) = self.recording.take().ok_or((len - 1, ParseError::InvalidDirective))?;
+                self.macros.insert(name.clone_string(), recording);
+            }
+            _ => return Err((len - 1, ParseError::InvalidDirective)),
+        }
+
+        Ok(())
+    }
+
+    /// Everything after the label
+    fn statement(&mut self, tokens: &[Token], start: usize) -> Result<(), Error> {
+        let len = tokens.len();
+        let Some(first) = tokens.get(start) else {
+            return Ok(());
+        };
+        let keyword = first.as_str().to_ascii_lowercase();
+        let err = |i: usize, e: ParseError| (len - i - 1, e);
+
+        if let Some(unit) = data_size(&keyword) {
+            return if keyword.starts_with("res") {
+                let count = self.number(tokens, start + 1)?;
+                let size = usize::try_from(count).map_err(|_| err(start + 1, ParseError::InvalidOperand))? * unit;
+                self.lines.push(Line::Directive(Directive::Space(size)));
+                self.advance(size);
+                Ok(())
+            } else {
+                self.data(tokens, start, unit)
+            };
+        }
+
+        match keyword.as_str() {
+            "times" => {
+                let (count, end) = self.eval(tokens, start + 1)?;
+                if count.section.is_some() || count.offset < 0 {
+                    return Err(err(start + 1, ParseError::InvalidOperand));
+                }
+                for _ in 0..count.offset {
+                    self.statement(tokens, end)?;
+                }
+            }
+            "section" | "segment" => {
+                // Names like .note.GNU-stack are split up by the lexer, attributes like align=16 are ignored for now
+                let mut name = tokens.get(start + 1).ok_or(err(start, ParseError::UnexpectedLB))?.clone_string();
+                let mut i = start + 2;
+                while i + 1 < len && tokens[i] == "-" {
+                    name = format!("{}-{}", name, tokens[i + 1].as_str());
+                    i += 2;
+                }
+
+                self.section = name.clone();
+                self.lines.push(Line::Directive(Directive::Section(name, None)));
+            }
+            "global" | "extern" => {
+                // Undefined symbols are external anyway
+                for (begin, end) in split_operands(tokens, start + 1) {
+                    let name =
+                        tokens.get(begin).filter(|_| begin < end).ok_or(err(begin, ParseError::InvalidDirective))?;
+                    let name = self.qualify(name.as_str());
+
+                    if keyword == "global" {
+                        // global main:function
+                        if end - begin > 2 && tokens[begin + 1] == ":" {
+                            let typ = match tokens[begin + 2].as_str().to_ascii_lowercase().as_str() {
+                                "function" => SymbolType::Function,
+                                "data" | "object" => SymbolType::Object,
+                                _ => SymbolType::Unknown,
+                            };
+                            self.lines.push(Line::Directive(Directive::Type(name.clone(), typ)));
+                        }
+                        self.lines.push(Line::Directive(Directive::Global(name)));
+                    }
+                }
+            }
+            "bits" => {
+                if self.number(tokens, start + 1)? != 64 {
+                    return Err(err(start + 1, ParseError::InvalidDirective));
+                }
+            }
+            "default" => {
+                let default_rel = match tokens.get(start + 1).map(|t| t.as_str().to_ascii_lowercase()).as_deref() {
+                    Some("rel") if len == start + 2 => true,
+                    Some("abs") if len == start + 2 => false,
+                    _ => return Err(err(start, ParseError::InvalidDirective)),
+                };
+                self.target.set_default_rel(default_rel).map_err(|_| err(start, ParseError::InvalidDirective))?;
+            }
+            _ if self.macros.contains_key(first.as_str()) => self.expand(tokens, start)?,
+            _ => self.instruction(tokens, start)?,
+        }
+
+        Ok(())
+    }
+
+    /// db, dw, dd and dq
+    fn data(&mut self, tokens: &[Token], start: usize, unit: usize) -> Result<(), Error> {
+        let len = tokens.len();
+        let mut bytes = Vec::new();
+        // Values that depend on symbols are left to the assembler, between the bytes of the others
+        let mut directives = Vec::new();
+        let mut size = 0;
+
+        let operands = split_operands(tokens, start + 1);
+        if operands.is_empty() {
+            return Err((len - start - 1, ParseError::UnexpectedLB));
+        }
+
+        for (begin, end) in operands {
+            if begin == end {
+                return Err((len - begin.min(len - 1) - 1, ParseError::InvalidOperand));
+            }
+
+            // Strings are padded to a multiple of the unit size
+            if end - begin == 1 && tokens[begin].is_quoted() {
+                bytes.extend(tokens[begin].as_str().as_bytes());
+                bytes.resize(bytes.len().div_ceil(unit) * unit, 0);
+                continue;
+            }
+
+            if let Ok((Value { section: None, offset }, stop)) = self.eval(&tokens[..end], begin) {
+                if stop == end {
+                    bytes.extend(&offset.to_le_bytes()[..unit]);
+                    continue;
+                }
+            }
+
+            // Addresses of labels, like `dq main` or `dd msg + 4`, are written like in the GNU syntaxes
+            let mut translation = Vec::new();
+            (begin..end).for_each(|j| self.substitute(&tokens[j], j, &mut translation));
+            specifiers(&mut translation).map_err(|at| (len - at - 1, ParseError::InvalidOperand))?;
+            let gnu = translation.iter().map(|t| t.0.clone()).collect::<Vec<Token>>();
+            let value = expression::value(&gnu, 0).map_err(|(i, e)| {
+                let at = translation.get(gnu.len().saturating_sub(i + 1)).map(|t| t.1).unwrap_or(begin);
+                (len - at - 1, e)
+            })?;
+
+            if value.is_constant() {
+                bytes.extend(&value.addend.to_le_bytes()[..unit]);
+                continue;
+            }
+            if !bytes.is_empty() {
+                size += bytes.len();
+                directives.push(Directive::Bytes(std::mem::take(&mut bytes)));
+            }
+            size += unit;
+            directives.push(Directive::Data(unit, vec![DataValue::Reference(value)]));
+        }
+
+        if !bytes.is_empty() || directives.is_empty() {
+            size += bytes.len();
+            directives.push(Directive::Bytes(bytes));
+        }
+        self.advance(size);
+        self.lines.extend(directives.into_iter().map(Line::Directive));
+        Ok(())
+    }
+
+    /// Expands a macro, replacing %1, %2 and so on with the arguments and giving %%labels unique names
+    fn expand(&mut self, tokens: &[Token], start: usize) -> Result<(), Error> {
+        let len = tokens.len();
+        let recording = &self.macros[tokens[start].as_str()];
+        let args = split_operands(tokens, start + 1);
+        if args.len() != recording.params {
+            return Err((len - start - 1, ParseError::InvalidOperand));
+        }
+
+        let body = recording.body.clone();
+        self.unique += 1;
+        let id = self.unique;
+
+        for line in body {
+            let mut expanded = Vec::new();
+            for token in line {
+                let raw = token.as_str();
+                if let Some(label) = raw.strip_prefix("%%") {
+                    expanded.push(Token::new(&format!("..@{}.{}", id, label)));
+                } else if raw == "%0" {
+                    expanded.push(Token::new(&args.len().to_string()));
+                } else if let Some(n) = raw.strip_prefix('%').and_then(|n| n.parse::<usize>().ok()) {
+                    let (begin, end) =
+                        args.get(n.wrapping_sub(1)).ok_or((len - start - 1, ParseError::InvalidOperand))?;
+                    expanded.extend(tokens[*begin..*end].iter().cloned());
+                } else {
+                    expanded.push(token);
+                }
+            }
+
+            if !expanded.is_empty() {
+                // Errors point at the argument they came from, or at the invocation
+                if let Err((tok, e)) = self.process(expanded) {
+                    let at = tokens.iter().position(|t| t.get_pos().pos == tok.get_pos().pos).unwrap_or(start);
+                    return Err((len - at - 1, e));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push_number(n: i64, at: usize, out: &mut Vec<(Token, usize)>) {
+        // The Intel parser takes negative numbers as a minus and a number
+        if n < 0 {
+            if out.last().is_some_and(|t| t.0 == "+") {
+                out.pop();
+            }
+            out.push((Token::new("-"), at));
+        }
+        out.push((Token::new(&n.unsigned_abs().to_string()), at));
+    }
+
+    /// Rewrites a single token of an operand
+    fn substitute(&mut self, token: &Token, at: usize, out: &mut Vec<(Token, usize)>) {
+        let raw = token.as_str();
+        let number = if token.is_quoted() {
+            self.unary(std::slice::from_ref(token), &mut 0).ok().map(|v| v.offset)
+        } else {
+            parse_number(raw).or_else(|| self.constants.get(&self.qualify(raw)).copied())
+        };
+
+        if let Some(n) = number {
+            Self::push_number(n, at, out);
+        } else if raw == "$" {
+            let label = self.unique_label("here");
+            self.lines.push(Line::Label(label.clone()));
+            out.push((Token::new(&label), at));
+        } else {
+            out.push((Token::new(&self.qualify(raw)), at));
+        }
+    }
+
+    /// Rewrites a memory operand from the opening bracket, returning the index after the closing one
+    fn memory(&mut self, tokens: &[Token], open: usize, out: &mut Vec<(Token, usize)>) -> Result<usize, Error> {
+        let len = tokens.len();
+        let close = (open..len).find(|i| tokens[*i] == "]").ok_or((len - open - 1, ParseError::InvalidOperand))?;
+
+        // Default rel mode is up to the target, which also understands [abs x]
+        let mut i = open + 1;
+        let keyword = tokens.get(i).map(|t| t.as_str().to_ascii_lowercase());
+        let (rel, abs) = (keyword.as_deref() == Some("rel"), keyword.as_deref() == Some("abs"));
+        if rel || abs {
+            i += 1;
+        }
+
+        // [fs:0x28] is written fs:[0x28] in Intel syntax
+        if i + 1 < close && tokens[i + 1] == ":" && SEGMENTS.contains(&tokens[i].as_str().to_ascii_lowercase().as_str())
+        {
+            out.push((tokens[i].clone(), i));
+            out.push((tokens[i + 1].clone(), i + 1));
+            i += 2;
+        }
+
+        // Only addresses of symbols, without registers, are relative to rip
+        let inner = &tokens[i..close];
+        let has_register = inner.iter().any(|t| self.target.is_register(&t.as_str().to_ascii_lowercase()));
+        let has_symbol = inner.iter().any(|t| {
+            let raw = t.as_str();
+            raw.starts_with(|c: char| c.is_alphabetic() || c == '.' || c == '_' || c == '$')
+                && !self.constants.contains_key(&self.qualify(raw))
+                && !self.target.is_register(&raw.to_ascii_lowercase())
+        });
+
+        out.push((tokens[open].clone(), open));
+        if abs {
+            out.push((tokens[open + 1].clone(), open + 1));
+        } else if rel && has_symbol && !has_register {
+            out.push((Token::new("rip"), open));
+            out.push((Token::new("+"), open));
+        }
+        for (j, token) in inner.iter().enumerate() {
+            self.substitute(token, i + j, out);
+        }
+        out.push((tokens[close].clone(), close));
+
+        Ok(close + 1)
+    }
+
+    fn instruction(&mut self, tokens: &[Token], start: usize) -> Result<(), Error> {
+        let len = tokens.len();
+        let mut translation = vec![(tokens[start].clone(), start)];
+
+        for (n, (begin, end)) in split_operands(tokens, start + 1).into_iter().enumerate() {
+            if n != 0 {
+                translation.push((tokens[begin - 1].clone(), begin - 1));
+            }
+
+            // Size keywords go without ptr, and can also be hints for immediates. Jump distances are left to the
+            // assembler
+            let mut i = begin;
+            let mut size = None;
+            while i < end {
+                let word = tokens[i].as_str().to_ascii_lowercase();
+                if SIZES.contains(&word.as_str()) {
+                    size = Some(i);
+                } else if !["short", "near", "strict"].contains(&word.as_str()) {
+                    break;
+                }
+                i += 1;
+            }
+
+            if i == end {
+                return Err((len - end.min(len - 1) - 1, ParseError::InvalidOperand));
+            }
+
+            if tokens[i] == "[" {
+                if let Some(size) = size {
+                    translation.push((tokens[size].clone(), size));
+                    translation.push((Token::new("ptr"), size));
+                }
+                let after = self.memory(&tokens[..end], i, &mut translation).map_err(|(j, e)| (j + len - end, e))?;
+                if after != end {
+                    return Err((len - after - 1, ParseError::InvalidOperand));
+                }
+            } else if let Ok((Value { section: None, offset }, stop)) = self.eval(&tokens[..end], i) {
+                // Constant expressions are folded, everything else is left to the Intel parser
+                if stop == end {
+                    Self::push_number(offset, i, &mut translation);
+                } else {
+                    (i..end).for_each(|j| self.substitute(&tokens[j], j, &mut translation));
+                }
+            } else {
+                (i..end).for_each(|j| self.substitute(&tokens[j], j, &mut translation));
+            }
+        }
+
+        specifiers(&mut translation).map_err(|at| (len - at - 1, ParseError::InvalidOperand))?;
+
+        let intel = translation.iter().map(|t| t.0.clone()).collect::<Vec<Token>>();
+        let instruction = self.target.parse_instruction(&intel).map_err(|(i, e)| {
+            let at = translation.get(intel.len().saturating_sub(i + 1)).map(|t| t.1).unwrap_or(len - 1);
+            (len - at - 1, e)
+        })?;
+
+        let encoded = instruction.encode().map_err(|_| (0, ParseError::InvalidOperand))?;
+        self.advance(encoded.get_bytes().len());
+        self.lines.push(Line::Instruction(instruction));
+        Ok(())
+    }
+}
+
+pub fn parse_lines(file_name: String, code: String, target: &mut dyn Target) -> Result<Vec<Line>, ()> {
+    let mut nasm = Nasm::new(target);
+    let mut is_ok = true;
+
+    for tokens in lex_lines(strip_comments(&code), false) {
+        let mnemonic = tokens[0].clone_string();
+        if let Err((tok, e)) = nasm.process(tokens) {
+            report_error(&file_name, &code, &tok, e, &mnemonic, nasm.target);
+            is_ok = false;
+        }
+    }
+
+    if let Some((start, _)) = nasm.recording.take() {
+        report_error(&file_name, &code, &start, ParseError::UnexpectedEOF, "", nasm.target);
+        is_ok = false;
+    }
+
+    if is_ok {
+        Ok(nasm.lines)
+    } else {
+        Err(())
+    }
+}
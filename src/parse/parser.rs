@@ -4,27 +4,284 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use crate::parse::encodings::matches;
-use crate::parse::helpers::get_next;
-use crate::parse::lexer::{Lexer, Token};
-use crate::parse::{BuildVersion, Directive, Line, ParseError};
-use ariadne::{Label, Report, ReportKind, Source};
-
-fn sanitize_string(mut string: String) -> String {
-    string = string.replace("\\a", "\x07");
-    string = string.replace("\\b", "\x08");
-    string = string.replace("\\t", "\x09");
-    string = string.replace("\\n", "\x0A");
-    string = string.replace("\\v", "\x0B");
-    string = string.replace("\\f", "\x0C");
-    string = string.replace("\\r", "\x0D");
-    string = string.replace("\\e", "\x1B");
-    string = string.replace("\\?", "\x3F");
-
-    string
-}
-
-fn parse_directive(tokens: &Vec<Token>) -> Result<Line, (usize, ParseError)> {
+use crate::number::FloatFormat;
+use crate::parse::expression::{self, evaluate};
+use crate::parse::helpers::{get_next, parse_integer};
+use crate::parse::lexer::{Lexer, Token, OPERATORS};
+use crate::parse::{
+    Alignment, BuildVersion, DataValue, Directive, Line, ParseError, SectionAttributes, SymbolSize, SymbolType,
+    SymbolValue, Syntax,
+};
+use crate::target::Target;
+use ariadne::{Color, Label, Report, ReportKind, Source};
+use object::elf;
+use std::collections::HashMap;
+
+/// Decodes the escapes of a string, which can make bytes that aren't UTF-8, like `\377`. Like GNU as, octal
+/// escapes take up to three digits, hex ones as many as there are, and unknown ones stand for the character itself
+fn unescape(string: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(string.len());
+    let mut chars = string.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            continue;
+        }
+
+        let byte = match chars.next() {
+            Some(digit @ '0'..='7') => {
+                let mut n = digit.to_digit(8).unwrap();
+                for _ in 0..2 {
+                    match chars.next_if(|c| c.is_digit(8)) {
+                        Some(digit) => n = n * 8 + digit.to_digit(8).unwrap(),
+                        None => break,
+                    }
+                }
+                n as u8
+            }
+            Some('x' | 'X') => {
+                let mut n = 0u8;
+                while let Some(digit) = chars.next_if(|c| c.is_ascii_hexdigit()) {
+                    n = n.wrapping_mul(16).wrapping_add(digit.to_digit(16).unwrap() as u8);
+                }
+                n
+            }
+            Some('a') => 0x07,
+            Some('b') => 0x08,
+            Some('t') => 0x09,
+            Some('n') => 0x0A,
+            Some('v') => 0x0B,
+            Some('f') => 0x0C,
+            Some('r') => 0x0D,
+            Some('e') => 0x1B,
+            Some(other) => {
+                bytes.extend_from_slice(other.encode_utf8(&mut [0; 4]).as_bytes());
+                continue;
+            }
+            None => b'\\',
+        };
+        bytes.push(byte);
+    }
+    bytes
+}
+
+/// Width in bytes of the characters of string directives, and whether they end with a zero character
+fn string_format(directive: &str) -> Option<(usize, bool)> {
+    match directive {
+        ".ascii" => Some((1, false)),
+        ".asciz" | ".string" => Some((1, true)),
+        ".string16" => Some((2, true)),
+        ".string32" => Some((4, true)),
+        _ => None,
+    }
+}
+
+/// Size in bytes of the values of the data directives
+fn data_size(directive: &str) -> Option<usize> {
+    match directive {
+        ".byte" => Some(1),
+        ".short" | ".word" => Some(2),
+        ".long" | ".int" => Some(4),
+        ".quad" => Some(8),
+        ".octa" => Some(16),
+        _ => None,
+    }
+}
+
+fn float_format(directive: &str) -> Option<FloatFormat> {
+    match directive {
+        ".float" | ".single" => Some(FloatFormat::Single),
+        ".double" => Some(FloatFormat::Double),
+        ".tfloat" => Some(FloatFormat::Extended),
+        _ => None,
+    }
+}
+
+/// Evaluates the comma separated constant arguments of a directive
+fn constant_arguments(tokens: &[Token]) -> Result<Vec<i64>, (usize, ParseError)> {
+    let mut arguments = Vec::new();
+    let mut start = 1;
+    for part in tokens[1..].split(|t| t == ",") {
+        arguments.push(evaluate(part, tokens.len() - start - part.len())?);
+        start += part.len() + 1;
+    }
+    Ok(arguments)
+}
+
+/// The flags, type and entry size after the name in `.section .rodata.str1.1,"aMS",@progbits,1`, where `tokens`
+/// starts at the flags. Anything after those, like the group of a section, is ignored
+fn section_attributes(tokens: &[Token]) -> Result<SectionAttributes, (usize, ParseError)> {
+    let err = |i: usize| (tokens.len().saturating_sub(i + 1), ParseError::InvalidDirective);
+    let mut parts = tokens.split(|t| t == ",");
+
+    let flags = match parts.next() {
+        Some([flags]) if flags.is_quoted() => flags.as_str(),
+        _ => return Err(err(0)),
+    };
+    let mut attributes = SectionAttributes { flags: 0, typ: None, entsize: 0 };
+    for flag in flags.chars() {
+        attributes.flags |= match flag {
+            'a' => elf::SHF_ALLOC,
+            'w' => elf::SHF_WRITE,
+            'x' => elf::SHF_EXECINSTR,
+            'M' => elf::SHF_MERGE,
+            'S' => elf::SHF_STRINGS,
+            'T' => elf::SHF_TLS,
+            // Groups, link order and retained sections aren't supported, the section is written without them
+            'G' | 'o' | 'R' | '?' => 0,
+            _ => return Err(err(0)),
+        } as u64;
+    }
+
+    match parts.next() {
+        None => return Ok(attributes),
+        Some([typ]) => {
+            let typ = match typ.as_str().trim_start_matches(['@', '%']) {
+                "progbits" => elf::SHT_PROGBITS,
+                "nobits" => elf::SHT_NOBITS,
+                "note" => elf::SHT_NOTE,
+                "init_array" => elf::SHT_INIT_ARRAY,
+                "fini_array" => elf::SHT_FINI_ARRAY,
+                "preinit_array" => elf::SHT_PREINIT_ARRAY,
+                _ => return Err(err(2)),
+            };
+            attributes.typ = Some(typ);
+        }
+        _ => return Err(err(2)),
+    }
+
+    // Only sections the linker merges have an entry size
+    if attributes.flags & elf::SHF_MERGE as u64 != 0 {
+        let entsize = parts.next().ok_or(err(2))?;
+        let entsize = evaluate(entsize, tokens.len() - 4 - entsize.len())?;
+        attributes.entsize = u64::try_from(entsize).map_err(|_| err(4))?;
+    }
+    Ok(attributes)
+}
+
+/// The symbol, size and alignment of space reserved with `.lcomm buf, 64` or `.zerofill __DATA,__bss,_buf,64,4`,
+/// where `tokens` starts at the symbol
+fn reserved_symbol(
+    tokens: &[Token],
+    alignment: fn(u64) -> Alignment,
+) -> Result<(String, usize, Alignment), (usize, ParseError)> {
+    let err = (tokens.len().saturating_sub(1), ParseError::InvalidDirective);
+    if tokens.len() < 3 || tokens[1] != "," || tokens[0].is_quoted() {
+        return Err(err);
+    }
+
+    // The symbol takes the place of the directive
+    let (size, align) = match constant_arguments(&tokens[1..])?[..] {
+        [size] => (size, None),
+        [size, align] => (size, Some(u64::try_from(align).map_err(|_| (0, ParseError::InvalidDirective))?)),
+        _ => return Err(err),
+    };
+    let size = usize::try_from(size).map_err(|_| err.clone())?;
+
+    // Like GNU as, the default is the largest power of two up to 8 that fits in the size
+    let alignment = match align {
+        Some(align) => alignment(align),
+        None => Alignment::Bytes([8, 4, 2].into_iter().find(|a| size >= *a).unwrap_or(1) as u64),
+    };
+    Ok((tokens[0].clone_string(), size, alignment))
+}
+
+/// Integers in data can be as wide as `.octa`, and are often written unsigned, like `.quad 0xffffffffffffffff`
+fn parse_data_integer(s: &str) -> Option<i128> {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u128::from_str_radix(hex, 16).ok().map(|n| n as i128),
+        None => s.parse::<u128>().ok().map(|n| n as i128),
+    }
+}
+
+/// The name of the symbol a token stands for, if it isn't a number or an operator
+fn symbol_name(t: &Token) -> Option<String> {
+    let is_symbol = !OPERATORS.contains(&t.as_str()) && !t.as_str().starts_with(|c: char| c.is_ascii_digit());
+    is_symbol.then(|| t.clone_string())
+}
+
+/// Parses one value of a data directive, a number too wide for expressions or an expression that needs a symbol to
+/// be added
+fn parse_data_value(tokens: &[Token]) -> Option<DataValue> {
+    let wide = match tokens {
+        [value] => parse_data_integer(value.as_str()),
+        [minus, number] if *minus == "-" => parse_data_integer(number.as_str()).map(i128::wrapping_neg),
+        _ => None,
+    };
+    if let Some(n) = wide {
+        return Some(DataValue::Integer(n));
+    }
+
+    match expression::value(tokens, 0).ok()? {
+        value if value.is_constant() => Some(DataValue::Integer(value.addend as i128)),
+        value if value.symbol.is_some() => Some(DataValue::Reference(value)),
+        _ => None,
+    }
+}
+
+/// Parses the value of `.set`, `.equ` or `=`, which is a constant expression or another symbol
+fn parse_symbol_value(tokens: &[Token]) -> Result<SymbolValue, (usize, ParseError)> {
+    match tokens {
+        [to] if *to != "." && parse_integer(to.as_str()).is_none() => {
+            symbol_name(to).map(SymbolValue::Symbol).ok_or((0, ParseError::InvalidOperand))
+        }
+        _ => Ok(SymbolValue::Constant(evaluate(tokens, 0)?)),
+    }
+}
+
+/// Whether the arguments of a directive are values, which can be constants defined with `.set`
+fn takes_values(directive: &str) -> bool {
+    let values = [
+        ".align", ".balign", ".p2align", ".space", ".skip", ".zero", ".fill", ".org", ".nops", ".if", ".elseif",
+    ];
+    data_size(directive).is_some() || values.contains(&directive)
+}
+
+/// Replaces constants defined with `.set` or `=` by their values, like NASM's `equ`. `symbols` has every symbol
+/// defined so far, and the values of the constant ones
+fn substitute_constants(tokens: Vec<Token>, symbols: &HashMap<String, Option<i64>>) -> Vec<Token> {
+    // Names of labels and directives stay as they are
+    let start = match tokens.first().map(|t| t.as_str()) {
+        _ if tokens.last().unwrap() == ":" => return tokens,
+        _ if tokens.get(1).is_some_and(|t| t == "=") => 2,
+        Some(".set" | ".equ" | ".equiv" | ".eqv") => 2,
+        Some(directive) if directive.starts_with('.') && !takes_values(directive) => return tokens,
+        _ => 1,
+    };
+
+    let mut substituted = Vec::with_capacity(tokens.len());
+    for (i, token) in tokens.into_iter().enumerate() {
+        // Immediates in AT&T syntax start with $, like `$BUFSIZE`
+        let (dollar, name) = token.as_str().strip_prefix('$').map_or((false, token.as_str()), |name| (true, name));
+        let value = match symbols.get(name) {
+            Some(Some(value)) if i >= start && !token.is_quoted() => *value,
+            _ => {
+                substituted.push(token);
+                continue;
+            }
+        };
+
+        if dollar {
+            substituted.push(token.replaced("$"));
+        }
+        // The parsers take negative numbers as a minus and a number
+        if value < 0 {
+            match substituted.last() {
+                Some(sign) if *sign == "+" => *substituted.last_mut().unwrap() = token.replaced("-"),
+                Some(sign) if *sign == "-" => *substituted.last_mut().unwrap() = token.replaced("+"),
+                _ => substituted.push(token.replaced("-")),
+            }
+        }
+        substituted.push(token.replaced(&value.unsigned_abs().to_string()));
+    }
+    substituted
+}
+
+fn parse_directive(
+    tokens: &[Token],
+    target: &mut dyn Target,
+    symbols: &HashMap<String, Option<i64>>,
+) -> Result<Line, (usize, ParseError)> {
     if tokens.is_empty() {
         Err((0, ParseError::UnexpectedLB))
     } else {
@@ -35,10 +292,196 @@ fn parse_directive(tokens: &Vec<Token>) -> Result<Line, (usize, ParseError)> {
         }
 
         match first.unwrap().as_str() {
-            ".asciz" => {
-                let string = get_next(&mut iter)?.clone_string();
+            ".arch" => {
+                // Names like x86-64-v2 are split up by the lexer
+                get_next(&mut iter.clone())?;
+                let arch = iter.map(|t| t.as_str()).collect::<String>();
+                target.set_arch(&arch).map_err(|_| (tokens.len() - 2, ParseError::InvalidDirective))?;
+
+                Ok(Line::Directive(Directive::Arch))
+            }
+            ".att_syntax" | ".intel_syntax" => {
+                let syntax = if first.unwrap() == ".att_syntax" { Syntax::Att } else { Syntax::Intel };
+
+                // Like GNU as for ELF, registers need a % in AT&T syntax, and in Intel syntax only if asked for
+                let prefix = match iter.next().map(|t| t.as_str()) {
+                    None => syntax == Syntax::Att,
+                    Some("prefix") => true,
+                    Some("noprefix") => false,
+                    Some(_) => return Err((tokens.len() - 2, ParseError::InvalidDirective)),
+                };
+                if iter.next().is_some() {
+                    return Err((iter.count(), ParseError::InvalidDirective));
+                }
+                // The arguments are checked before switching, so a directive that fails leaves the syntax as it was
+                if syntax == Syntax::Att && !prefix {
+                    return Err((0, ParseError::InvalidDirective));
+                }
+                target.set_syntax(syntax).map_err(|_| (tokens.len() - 1, ParseError::InvalidDirective))?;
+                target.set_register_prefix(prefix).map_err(|_| (0, ParseError::InvalidDirective))?;
+
+                Ok(Line::Directive(Directive::Syntax))
+            }
+            ".align" | ".balign" | ".p2align" => {
+                // The fill can be left out to give only a maximum, like `.p2align 4,,10`
+                let mut arguments = Vec::new();
+                let mut start = 1;
+                for part in tokens[1..].split(|t| t == ",") {
+                    let err = (tokens.len().saturating_sub(start + 1), ParseError::InvalidDirective);
+                    arguments.push(match part {
+                        [] if start > 1 => None,
+                        [] => return Err(err),
+                        _ => Some(evaluate(part, tokens.len() - start - part.len())?),
+                    });
+                    start += part.len() + 1;
+                }
+
+                let (boundary, fill, max_skip) = match arguments[..] {
+                    [Some(boundary)] => (boundary, None, None),
+                    [Some(boundary), fill] => (boundary, fill, None),
+                    [Some(boundary), fill, max_skip] => (boundary, fill, max_skip),
+                    _ => return Err((tokens.len() - 1, ParseError::InvalidDirective)),
+                };
+                let boundary = u64::try_from(boundary).map_err(|_| (tokens.len() - 2, ParseError::InvalidDirective))?;
+                let alignment = match first.unwrap().as_str() {
+                    ".balign" => Alignment::Bytes(boundary),
+                    ".p2align" => Alignment::PowerOfTwo(boundary),
+                    _ => Alignment::Either(boundary),
+                };
+                if !matches!(alignment, Alignment::Either(_)) && alignment.bytes(false).is_none() {
+                    return Err((tokens.len() - 2, ParseError::InvalidDirective));
+                }
+
+                // Like GNU as, only the lowest byte of the fill is used, and a maximum of 0 is none
+                let max_skip = max_skip.filter(|max| *max != 0).map(|max| max as usize);
+                Ok(Line::Directive(Directive::Align(alignment, fill.map(|fill| fill as u8), max_skip)))
+            }
+            ".space" | ".skip" | ".zero" => {
+                let err = (tokens.len() - 1, ParseError::InvalidDirective);
+                let (size, fill) = match (first.unwrap().as_str(), &constant_arguments(tokens)?[..]) {
+                    (_, [size]) => (*size, 0),
+                    (".space" | ".skip", [size, fill]) => (*size, *fill),
+                    _ => return Err(err),
+                };
+                let size = usize::try_from(size).map_err(|_| err.clone())?;
+
+                // Like GNU as, only the lowest byte of the fill is used
+                if fill == 0 {
+                    Ok(Line::Directive(Directive::Space(size)))
+                } else {
+                    Ok(Line::Directive(Directive::Bytes(vec![fill as u8; size])))
+                }
+            }
+            ".fill" => {
+                let err = (tokens.len() - 1, ParseError::InvalidDirective);
+                let (repeat, size, value) = match constant_arguments(tokens)?[..] {
+                    [repeat] => (repeat, 1, 0),
+                    [repeat, size] => (repeat, size, 0),
+                    [repeat, size, value] => (repeat, size, value),
+                    _ => return Err(err),
+                };
+                let repeat = usize::try_from(repeat).map_err(|_| err.clone())?;
+                let size = usize::try_from(size).map_err(|_| err.clone())?.min(8);
+
+                // GNU as only takes 4 bytes of the value, the ones above them are zero
+                let pattern = (value as u32 as u64).to_le_bytes();
+                Ok(Line::Directive(Directive::Bytes(pattern[..size].repeat(repeat))))
+            }
+            ".org" => {
+                let err = (tokens.len() - 1, ParseError::InvalidDirective);
+                let mut arguments = tokens[1..].split(|t| t == ",");
+                let (location, fill) = match (arguments.next(), arguments.next(), arguments.next()) {
+                    (Some(location), fill, None) => (location, fill),
+                    _ => return Err(err),
+                };
+                let fill = match fill {
+                    Some(fill) => evaluate(fill, 0)?,
+                    None => 0,
+                };
+
+                // The location is a constant or relative to `.`, like `.org . + 4`
+                let after = tokens.len() - 1 - location.len();
+                let location = expression::value(location, after)?;
+                let relative = location.symbol.as_deref() == Some(".") && location.specifier.is_none();
+                if !(location.is_constant() || relative && location.minus.is_none()) {
+                    return Err(err);
+                }
+                if !relative && location.addend < 0 {
+                    return Err(err);
+                }
 
-                Ok(Line::Directive(Directive::Asciz(sanitize_string(string))))
+                Ok(Line::Directive(Directive::Org(location, fill as u8)))
+            }
+            ".nops" => {
+                let err = (tokens.len() - 1, ParseError::InvalidDirective);
+                let arguments = constant_arguments(tokens)?
+                    .into_iter()
+                    .map(|n| usize::try_from(n).map_err(|_| err.clone()))
+                    .collect::<Result<Vec<usize>, _>>()?;
+                let (count, limit) = match arguments[..] {
+                    [count] => (count, None),
+                    [count, limit] => (count, Some(limit).filter(|limit| *limit != 0)),
+                    _ => return Err(err),
+                };
+
+                Ok(Line::Directive(Directive::Bytes(target.nops(count, limit).ok_or(err)?)))
+            }
+            name if string_format(name).is_some() => {
+                let (width, terminated) = string_format(name).unwrap();
+
+                let mut bytes = Vec::new();
+                let mut start = 1;
+                for part in tokens[1..].split(|t| t == ",") {
+                    let string = match part {
+                        [string] if string.is_quoted() => string,
+                        _ => return Err((tokens.len().saturating_sub(start + 1), ParseError::InvalidDirective)),
+                    };
+
+                    let mut characters = unescape(string.as_str());
+                    if terminated {
+                        characters.push(0);
+                    }
+                    // Wide strings take every byte as its own character
+                    for character in characters {
+                        bytes.extend_from_slice(&(character as u32).to_le_bytes()[..width]);
+                    }
+                    start += part.len() + 1;
+                }
+
+                Ok(Line::Directive(Directive::Bytes(bytes)))
+            }
+            name if float_format(name).is_some() => {
+                let format = float_format(name).unwrap();
+
+                let mut bytes = Vec::new();
+                let mut start = 1;
+                for part in tokens[1..].split(|t| t == ",") {
+                    // The lexer splits signs and exponents like 1.5e-3 into several tokens
+                    let literal = part.iter().map(|t| t.as_str()).collect::<String>();
+                    let err = (tokens.len().saturating_sub(start + 1), ParseError::InvalidDirective);
+                    bytes.extend(format.encode(&literal).ok_or(err)?);
+                    start += part.len() + 1;
+                }
+
+                Ok(Line::Directive(Directive::Bytes(bytes)))
+            }
+            name if data_size(name).is_some() => {
+                let size = data_size(name).unwrap();
+
+                let mut values = Vec::new();
+                let mut start = 1;
+                for part in tokens[1..].split(|t| t == ",") {
+                    let err = (tokens.len().saturating_sub(start + 1), ParseError::InvalidDirective);
+                    let value = parse_data_value(part).ok_or(err.clone())?;
+                    // There are no relocations as wide as .octa
+                    if size == 16 && !matches!(value, DataValue::Integer(_)) {
+                        return Err(err);
+                    }
+                    values.push(value);
+                    start += part.len() + 1;
+                }
+
+                Ok(Line::Directive(Directive::Data(size, values)))
             }
             ".build_version" => {
                 let os = iter.next();
@@ -73,7 +516,56 @@ fn parse_directive(tokens: &Vec<Token>) -> Result<Line, (usize, ParseError)> {
                     Err((iter.count(), ParseError::UnexpectedLB))
                 }
             }
+            ".insn" => Ok(Line::Instruction(target.parse_raw_instruction(tokens)?)),
             ".globl" => Ok(Line::Directive(Directive::Global(get_next(&mut iter)?.clone_string()))),
+            ".set" | ".equ" | ".equiv" | ".eqv" => {
+                let name = match tokens {
+                    [_, name, comma, _, ..] if comma == "," => name,
+                    _ => return Err((tokens.len().saturating_sub(2), ParseError::InvalidDirective)),
+                };
+                // Unlike .set and .equ, .equiv and .eqv can't redefine symbols
+                if matches!(first.unwrap().as_str(), ".equiv" | ".eqv") && symbols.contains_key(name.as_str()) {
+                    return Err((tokens.len() - 2, ParseError::SymbolRedefined(name.clone_string())));
+                }
+
+                let value = parse_symbol_value(&tokens[3..])?;
+                Ok(Line::Directive(Directive::Set(name.clone_string(), value)))
+            }
+            ".comm" => {
+                let (name, size, alignment) = reserved_symbol(&tokens[1..], Alignment::Either)?;
+                Ok(Line::Directive(Directive::Common(name, size, alignment)))
+            }
+            ".local" => Ok(Line::Directive(Directive::Local(get_next(&mut iter)?.clone_string()))),
+            ".lcomm" => {
+                let symbol = reserved_symbol(&tokens[1..], Alignment::Either)?;
+                Ok(Line::Directive(Directive::Zerofill { section: None, symbol: Some(symbol) }))
+            }
+            ".zerofill" => {
+                let (segment, section) = match tokens {
+                    [_, segment, comma, section, ..] if comma == "," => (segment, section),
+                    _ => return Err((tokens.len() - 1, ParseError::InvalidDirective)),
+                };
+                let symbol = match &tokens[4..] {
+                    [] => None,
+                    [comma, rest @ ..] if comma == "," => Some(reserved_symbol(rest, Alignment::PowerOfTwo)?),
+                    _ => return Err((tokens.len() - 5, ParseError::InvalidDirective)),
+                };
+
+                let section = Some(format!("{},{}", segment.as_str(), section.as_str()));
+                Ok(Line::Directive(Directive::Zerofill { section, symbol }))
+            }
+            ".text" | ".data" | ".bss" => Ok(Line::Directive(Directive::Section(first.unwrap().clone_string(), None))),
+            ".section" if get_next(&mut iter.clone())?.as_str().starts_with('.') => {
+                // ELF section names may contain '-', which splits them into several tokens
+                let end = tokens.iter().position(|t| t == ",").unwrap_or(tokens.len());
+                let name = tokens[1..end].iter().map(|t| t.as_str()).collect::<String>();
+                let attributes = match tokens.get(end + 1..) {
+                    Some(rest) => Some(section_attributes(rest)?),
+                    None => None,
+                };
+
+                Ok(Line::Directive(Directive::Section(name, attributes)))
+            }
             ".section" => {
                 let segment = get_next(&mut iter)?.clone_string();
 
@@ -85,18 +577,56 @@ fn parse_directive(tokens: &Vec<Token>) -> Result<Line, (usize, ParseError)> {
 
                     let section = get_next(&mut iter)?.clone_string();
 
-                    Ok(Line::Directive(Directive::Section(format!("{},{}", segment, section))))
+                    Ok(Line::Directive(Directive::Section(format!("{},{}", segment, section), None)))
                 } else {
-                    Ok(Line::Directive(Directive::Section(segment)))
+                    Ok(Line::Directive(Directive::Section(segment, None)))
                 }
             }
+            ".size" => {
+                let name = get_next(&mut iter)?.clone_string();
+                if get_next(&mut iter)? != "," {
+                    return Err((iter.count(), ParseError::InvalidDirective));
+                }
+
+                let first = get_next(&mut iter)?.clone_string();
+                let size = if let Ok(size) = first.parse::<u64>() {
+                    SymbolSize::Constant(size)
+                } else if iter.next().is_some_and(|t| t == "-") {
+                    SymbolSize::Difference(first, get_next(&mut iter)?.clone_string())
+                } else {
+                    // Todo: other expressions
+                    return Err((tokens.len() - 4, ParseError::InvalidDirective));
+                };
+
+                if iter.next().is_some() {
+                    return Err((iter.count(), ParseError::InvalidDirective));
+                }
+
+                Ok(Line::Directive(Directive::Size(name, size)))
+            }
+            ".type" => {
+                let name = get_next(&mut iter)?.clone_string();
+                if get_next(&mut iter)? != "," {
+                    return Err((iter.count(), ParseError::InvalidDirective));
+                }
+
+                // The type can be written as @function, %function, "function" or STT_FUNC
+                let typ = match get_next(&mut iter)?.as_str().trim_start_matches(['@', '%']) {
+                    "function" | "STT_FUNC" => SymbolType::Function,
+                    "object" | "STT_OBJECT" => SymbolType::Object,
+                    // Todo: TLS, common and indirect functions
+                    _ => SymbolType::Unknown,
+                };
+
+                Ok(Line::Directive(Directive::Type(name, typ)))
+            }
             // Todo: parse other important directives like section and alignment indicators
             _ => Ok(Line::Directive(Directive::Unknown)),
         }
     }
 }
 
-fn parse_label(tokens: &Vec<Token>) -> Result<Line, (usize, ParseError)> {
+fn parse_label(tokens: &[Token]) -> Result<Line, (usize, ParseError)> {
     if tokens.len() == 1 {
         Err((0, ParseError::UnexpectedLB))
     } else {
@@ -109,26 +639,35 @@ fn parse_label(tokens: &Vec<Token>) -> Result<Line, (usize, ParseError)> {
     }
 }
 
-fn parse_instruction(tokens: &Vec<Token>) -> Result<Line, (usize, ParseError)> {
+fn parse_instruction(tokens: &[Token], target: &dyn Target) -> Result<Line, (usize, ParseError)> {
     // todo: instruction prefix (rep, lock)
-    Ok(Line::Instruction(matches(tokens)?))
+    Ok(Line::Instruction(target.parse_instruction(tokens)?))
 }
 
-fn parse_line(tokens: &Vec<Token>) -> Result<Line, (usize, ParseError)> {
-    if tokens.first().unwrap().as_str().starts_with('.') {
-        parse_directive(tokens)
-    } else if tokens.last().unwrap() == ":" {
+fn parse_line(
+    tokens: &[Token],
+    target: &mut dyn Target,
+    symbols: &HashMap<String, Option<i64>>,
+) -> Result<Line, (usize, ParseError)> {
+    // Labels are checked first, compilers name local ones .L2 or .LC0
+    if tokens.last().unwrap() == ":" {
         parse_label(tokens)
+    } else if tokens.first().unwrap().as_str().starts_with('.') {
+        parse_directive(tokens, target, symbols)
+    } else if tokens.len() > 2 && tokens[1] == "=" {
+        // `SYS_write = 1` is the same as `.set SYS_write, 1`
+        let name = symbol_name(&tokens[0]).ok_or((tokens.len() - 1, ParseError::InvalidOperand))?;
+        Ok(Line::Directive(Directive::Set(name, parse_symbol_value(&tokens[2..])?)))
     } else {
-        parse_instruction(tokens)
+        parse_instruction(tokens, target)
     }
 }
 
-pub fn parse_lines(file_name: String, code: String) -> Result<Vec<Line>, ()> {
-    let mut lexer = Lexer::new(code.clone());
+/// Splits source code into the tokens of each non-empty line. `escapes` is off for NASM, whose strings have none
+pub(crate) fn lex_lines(code: String, escapes: bool) -> Vec<Vec<Token>> {
+    let mut lexer = if escapes { Lexer::new(code) } else { Lexer::new(code).without_escapes() };
 
-    let mut vec = Vec::new();
-    let mut is_ok = true;
+    let mut lines = Vec::new();
 
     'outer_parser: loop {
         let mut tokens = Vec::new();
@@ -148,36 +687,192 @@ pub fn parse_lines(file_name: String, code: String) -> Result<Vec<Line>, ()> {
         }
 
         if !tokens.is_empty() {
-            let res = parse_line(&tokens);
-
-            if let Ok(res) = res {
-                vec.push(res);
-            } else if let Err((i, e)) = res {
-                let tok = tokens.remove(tokens.len() - i - 1);
-
-                let mut builder = Report::build(ReportKind::Error, file_name.clone(), tok.get_pos().pos)
-                    .with_code(e.to_code())
-                    .with_message(e.to_string())
-                    .with_label(
-                        Label::new((file_name.clone(), tok.get_range()))
-                            .with_message(format!("'{}' here", tok.as_str())),
-                    );
-
-                match e {
-                    ParseError::ExtraneousTokenBeforeLabel(label) => {
-                        builder = builder.with_label(
-                            Label::new((file_name.clone(), label.get_range()))
-                                .with_message(format!("To label '{}' here", label.as_str())),
-                        );
-                    }
-                    _ => {}
-                }
+            lines.push(tokens);
+        }
+    }
+
+    lines
+}
+
+/// Prints an error at `tok`, with the valid forms of `mnemonic` if the operands didn't match
+pub(crate) fn report_error(
+    file_name: &str,
+    code: &str,
+    tok: &Token,
+    e: ParseError,
+    mnemonic: &str,
+    target: &dyn Target,
+) {
+    let mut builder = Report::build(ReportKind::Error, file_name.to_string(), tok.get_pos().pos)
+        .with_code(e.to_code())
+        .with_message(e.to_string())
+        .with_label(
+            Label::new((file_name.to_string(), tok.get_range())).with_message(format!("'{}' here", tok.as_str())),
+        );
+
+    match e {
+        ParseError::ExtraneousTokenBeforeLabel(label) => {
+            builder = builder.with_label(
+                Label::new((file_name.to_string(), label.get_range()))
+                    .with_message(format!("To label '{}' here", label.as_str())),
+            );
+        }
+        ParseError::InvalidOperand | ParseError::ExtraneousTokenAfterInstruction | ParseError::UnexpectedLB => {
+            let forms = target.instruction_forms(Some(mnemonic));
+            if !forms.is_empty() {
+                let forms = forms.into_iter().map(|(form, _)| form).collect::<Vec<String>>();
+                builder = builder.with_note(format!("Valid forms are {}", forms.join(" | ")));
+            }
+        }
+        _ => {}
+    }
+
+    builder.finish().eprint((file_name.to_string(), Source::from(code.to_string()))).unwrap();
+}
+
+/// A block of conditional assembly, which assembles the lines of at most one of its branches
+struct Conditional {
+    /// The directive that opened the block, for the error if it's never closed
+    start: Token,
+    /// Whether the lines of the current branch are assembled
+    active: bool,
+    /// Whether a branch was taken already, so the ones after it aren't
+    taken: bool,
+    /// Whether the block is in its `.else` branch, which has to be the last one
+    otherwise: bool,
+}
+
+/// The text of a `.ifc` operand, where quotes only group
+fn text(tokens: &[Token]) -> String {
+    tokens.iter().map(|t| t.as_str()).collect()
+}
+
+/// Whether the condition of a `.if` like directive holds
+fn condition(tokens: &[Token], symbols: &HashMap<String, Option<i64>>) -> Result<bool, (usize, ParseError)> {
+    match (tokens[0].as_str(), &tokens[1..]) {
+        (".if" | ".elseif", expression) => Ok(evaluate(expression, 0)? != 0),
+        (".ifdef", [symbol]) => Ok(symbols.contains_key(symbol.as_str())),
+        (".ifndef", [symbol]) => Ok(!symbols.contains_key(symbol.as_str())),
+        (".ifc", operands) => {
+            let comma = operands.iter().position(|t| t == ",").ok_or((0, ParseError::InvalidDirective))?;
+            Ok(text(&operands[..comma]) == text(&operands[comma + 1..]))
+        }
+        (".ifeqs", [a, comma, b]) if a.is_quoted() && *comma == "," && b.is_quoted() => Ok(a.as_str() == b.as_str()),
+        _ => Err((tokens.len() - 1, ParseError::InvalidDirective)),
+    }
+}
+
+/// Handles the directives of conditional assembly, like `.ifdef MACHO` or `.endif`. Returns whether the line was one
+fn parse_conditional(
+    tokens: &[Token],
+    conditionals: &mut Vec<Conditional>,
+    symbols: &HashMap<String, Option<i64>>,
+) -> Result<bool, (usize, ParseError)> {
+    // Blocks in branches that aren't taken are tracked, but their conditions aren't looked at
+    let enclosing = |conditionals: &[Conditional]| conditionals.iter().all(|c| c.active);
 
-                builder.finish().eprint((file_name.clone(), Source::from(code.clone()))).unwrap();
+    match tokens[0].as_str() {
+        ".if" | ".ifdef" | ".ifndef" | ".ifc" | ".ifeqs" => {
+            let active = enclosing(conditionals) && condition(tokens, symbols)?;
+            conditionals.push(Conditional { start: tokens[0].clone(), active, taken: active, otherwise: false });
+        }
+        ".elseif" | ".else" => {
+            let (last, outer) = match conditionals.split_last_mut() {
+                Some((last, outer)) if !last.otherwise => (last, outer),
+                _ => return Err((tokens.len() - 1, ParseError::InvalidDirective)),
+            };
+            if tokens[0] == ".else" && tokens.len() > 1 {
+                return Err((tokens.len() - 2, ParseError::InvalidDirective));
+            }
 
-                is_ok = false;
+            last.active = enclosing(outer) && !last.taken && (tokens[0] == ".else" || condition(tokens, symbols)?);
+            last.taken |= last.active;
+            last.otherwise = tokens[0] == ".else";
+        }
+        ".endif" => {
+            if tokens.len() > 1 {
+                return Err((tokens.len() - 2, ParseError::InvalidDirective));
             }
+            conditionals.pop().ok_or((0, ParseError::InvalidDirective))?;
         }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Prints the message of `.error`, `.warning` or `.print` at the directive. Returns whether the line was one of them
+fn report_diagnostic(file_name: &str, code: &str, tokens: &[Token]) -> Result<bool, (usize, ParseError)> {
+    let kind = match tokens[0].as_str() {
+        ".error" => ReportKind::Error,
+        ".warning" => ReportKind::Warning,
+        ".print" => ReportKind::Custom("Print", Color::Cyan),
+        _ => return Ok(false),
+    };
+    let message = match &tokens[1..] {
+        [] if tokens[0] != ".print" => format!("{} directive invoked in source file", tokens[0].as_str()),
+        [message] if message.is_quoted() => String::from_utf8_lossy(&unescape(message.as_str())).to_string(),
+        _ => return Err((0, ParseError::InvalidDirective)),
+    };
+
+    let span = tokens[0].get_range().start..tokens.last().unwrap().get_range().end;
+    Report::build(kind, file_name.to_string(), span.start)
+        .with_message(message)
+        .with_label(Label::new((file_name.to_string(), span)).with_message(format!("'{}' here", tokens[0].as_str())))
+        .finish()
+        .eprint((file_name.to_string(), Source::from(code.to_string())))
+        .unwrap();
+    Ok(true)
+}
+
+pub fn parse_lines(file_name: String, code: String, target: &mut dyn Target) -> Result<Vec<Line>, ()> {
+    let mut vec = Vec::new();
+    let mut is_ok = true;
+    // Every symbol defined so far, with the value of constants, which are substituted in later lines
+    let mut symbols = HashMap::new();
+    // Open blocks of conditional assembly, the innermost last
+    let mut conditionals = Vec::new();
+
+    for tokens in lex_lines(code.clone(), true) {
+        let mut tokens = substitute_constants(tokens, &symbols);
+        let res = match parse_conditional(&tokens, &mut conditionals, &symbols) {
+            Ok(true) => continue,
+            Ok(false) if conditionals.iter().any(|c| !c.active) => continue,
+            Ok(false) => match report_diagnostic(&file_name, &code, &tokens) {
+                Ok(true) => {
+                    is_ok &= tokens[0] != ".error";
+                    continue;
+                }
+                Ok(false) => parse_line(&tokens, target, &symbols),
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
+        };
+
+        if let Ok(res) = res {
+            match &res {
+                Line::Label(name) => {
+                    symbols.insert(name.clone(), None);
+                }
+                Line::Directive(Directive::Set(name, value)) => {
+                    let constant = if let SymbolValue::Constant(value) = value { Some(*value) } else { None };
+                    symbols.insert(name.clone(), constant);
+                }
+                _ => {}
+            }
+            vec.push(res);
+        } else if let Err((i, e)) = res {
+            let mnemonic = tokens.first().unwrap().clone_string();
+            let tok = tokens.remove(tokens.len() - i - 1);
+            report_error(&file_name, &code, &tok, e, &mnemonic, target);
+
+            is_ok = false;
+        }
+    }
+
+    if let Some(conditional) = conditionals.last() {
+        let start = &conditional.start;
+        report_error(&file_name, &code, start, ParseError::UnexpectedEOF, start.as_str(), target);
+        is_ok = false;
     }
 
     if is_ok {
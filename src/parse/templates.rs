@@ -0,0 +1,469 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Instruction templates and the matcher working on them. The compiled in templates live in `encodings.rs`, extra ones
+//! can be loaded at runtime from files in the x86-csv format. Rows are interpreted with the same rules
+//! `generate_instructions.py` uses, so an extra table behaves as if it had been compiled in.
+
+use crate::instructions::{Instruction, Opcode, Operands, Register};
+use crate::parse::encodings::{MNEMONIC_INDEX, TEMPLATES};
+use crate::parse::features::FeatureSet;
+use crate::parse::helpers::*;
+use crate::parse::lexer::Token;
+use crate::parse::ParseError;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OperandKind {
+    Imm(usize),
+    Rel(usize),
+    /// A register or constant that has to be written exactly like this
+    Specific(&'static str),
+    Reg(usize),
+    Rm(usize),
+    M,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpcodePart {
+    Byte(u8),
+    /// An opcode byte, with the register operand added to it
+    PlusReg(u8),
+    Rex { wide: bool },
+    Imm,
+    Rel,
+}
+
+/// One form of an instruction, e.g. `imul r64, r/m64, imm32`. The tables of these are what the matcher works on, and
+/// what diagnostics and `--list-instructions` describe instructions with.
+#[derive(Debug, Clone)]
+pub struct InstructionTemplate {
+    pub mnemonic: &'static str,
+    pub operands: &'static [OperandKind],
+    /// Legacy prefixes written before the opcode, either mandatory ones or the operand size override
+    pub prefixes: &'static [u8],
+    pub opcode: &'static [OpcodePart],
+    /// The `/digit` placed into ModRM.reg, if the instruction has one
+    pub digit: Option<u8>,
+    /// The CPUID feature flag the instruction depends on
+    pub feature: Option<&'static str>,
+    pub description: &'static str,
+}
+
+impl Display for OperandKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OperandKind::Imm(size) => write!(f, "imm{}", size),
+            OperandKind::Rel(size) => write!(f, "rel{}", size),
+            OperandKind::Specific(s) => f.write_str(s),
+            OperandKind::Reg(size) => write!(f, "r{}", size),
+            OperandKind::Rm(size) => write!(f, "r/m{}", size),
+            OperandKind::M => f.write_str("m"),
+        }
+    }
+}
+
+impl Display for InstructionTemplate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.mnemonic)?;
+        for (i, op) in self.operands.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { " " } else { ", " }, op)?;
+        }
+        Ok(())
+    }
+}
+
+impl InstructionTemplate {
+    /// The encoding in the notation of the Intel manual, e.g. `REX.W 69 /r id`
+    pub fn encoding(&self) -> String {
+        let mut parts = self.prefixes.iter().map(|p| format!("{:02X}", p)).collect::<Vec<String>>();
+        let reg_size = self.operands.iter().find_map(|op| match op {
+            OperandKind::Reg(size) => Some(*size),
+            _ => None,
+        });
+        let mut imm_sizes = self.operands.iter().filter_map(|op| match op {
+            OperandKind::Imm(size) => Some(*size),
+            _ => None,
+        });
+        let rel_size = self.operands.iter().find_map(|op| match op {
+            OperandKind::Rel(size) => Some(*size),
+            _ => None,
+        });
+        let suffix = |size: Option<usize>, names: [&'static str; 4]| match size {
+            Some(8) => names[0],
+            Some(16) => names[1],
+            Some(32) => names[2],
+            _ => names[3],
+        };
+
+        for part in self.opcode {
+            match part {
+                OpcodePart::Rex { wide: true } => parts.push("REX.W".to_string()),
+                OpcodePart::Rex { wide: false } => parts.push("REX".to_string()),
+                OpcodePart::Byte(byte) => parts.push(format!("{:02X}", byte)),
+                OpcodePart::PlusReg(byte) => {
+                    parts.push(format!("{:02X}+{}", byte, suffix(reg_size, ["rb", "rw", "rd", "ro"])))
+                }
+                // Immediates follow ModRM, so they're added below
+                OpcodePart::Imm | OpcodePart::Rel => {}
+            }
+        }
+
+        let has_rm = self.operands.iter().any(|op| matches!(op, OperandKind::Rm(_) | OperandKind::M));
+        match self.digit {
+            Some(digit) => parts.push(format!("/{}", digit)),
+            None if has_rm => parts.push("/r".to_string()),
+            None => {}
+        }
+
+        for part in self.opcode {
+            match part {
+                OpcodePart::Imm => parts.push(suffix(imm_sizes.next(), ["ib", "iw", "id", "io"]).to_string()),
+                OpcodePart::Rel => parts.push(suffix(rel_size, ["cb", "cw", "cd", "cp"]).to_string()),
+                _ => {}
+            }
+        }
+
+        parts.join(" ")
+    }
+}
+
+const SPECIFIC_OPERANDS: [&str; 16] =
+    ["al", "ah", "ax", "eax", "rax", "cl", "ds", "dx", "es", "ss", "gs", "fs", "cs", "0", "1", "3"];
+
+const UNSUPPORTED_OPERANDS: [&str; 63] = [
+    "bnd", "bnd1", "bnd1/m64", "bnd1/m128", "CR0-CR7", "CR8", "DR0-DR7", "k1", "mem", "mm", "mm1", "moffs8", "moffs16",
+    "moffs32", "moffs64", "m8", "m16", "m16int", "m2byte", "m32", "m32fp", "m32int", "m512", "m512byte", "m64", "m64f",
+    "m64fp", "m64int", "m80bcd", "m80dec", "m80fp", "m128", "m14/28byte", "m16:16", "m16:32", "m16:64", "m16&16",
+    "m16&32", "m16&64", "m32&32", "m94/108byte", "ptr16:16", "ptr16:32", "reg", "rel", "r16/m16", "r32/m16", "r32/m32",
+    "r64/m16", "r64/m64", "Sreg", "ST(i)", "ST(0)", "vm32y", "vm32z", "vm64z", "xmm", "xmm1", "xmm1/m32", "xmm1/m64",
+    "xmm2", "xmm3/m128", "ymm1",
+];
+
+/// Legacy prefixes that select an instruction, instead of modifying it
+const MANDATORY_PREFIXES: [u8; 3] = [0x66, 0xF2, 0xF3];
+
+/// Instructions without 16-bit operands that still need the operand size override, or the other way around
+const SIZE_OVERRIDE: [&str; 9] = ["cbw", "cmpsw", "cwd", "insw", "lodsw", "movsw", "outsw", "scasw", "stosw"];
+const NO_SIZE_OVERRIDE: [&str; 7] = ["fnstsw", "fstsw", "lldt", "lmsw", "ltr", "verr", "verw"];
+
+fn needs_size_override(mnemonic: &str, operands: &[OperandKind]) -> bool {
+    if SIZE_OVERRIDE.contains(&mnemonic) {
+        return true;
+    }
+    if NO_SIZE_OVERRIDE.contains(&mnemonic) {
+        return false;
+    }
+    operands.contains(&OperandKind::Specific("ax"))
+        || matches!(operands.first(), Some(OperandKind::Reg(16)) | Some(OperandKind::Rm(16)))
+}
+
+fn leak<T>(vec: Vec<T>) -> &'static [T] {
+    // Templates live as long as the assembler does, just like the compiled in ones
+    Box::leak(vec.into_boxed_slice())
+}
+
+fn parse_operand(op: &str) -> Result<OperandKind, String> {
+    let raw = op.to_lowercase();
+    let size = |prefix: &str| raw[prefix.len()..].parse::<usize>().map_err(|_| format!("Invalid operand '{}'", op));
+
+    if raw.starts_with("imm") {
+        Ok(OperandKind::Imm(size("imm")?))
+    } else if raw.starts_with("rel") {
+        Ok(OperandKind::Rel(size("rel")?))
+    } else if let Some(specific) = SPECIFIC_OPERANDS.iter().find(|s| **s == raw) {
+        Ok(OperandKind::Specific(specific))
+    } else if raw.starts_with("r/m") {
+        Ok(OperandKind::Rm(size("r/m")?))
+    } else if raw.starts_with('r') {
+        Ok(OperandKind::Reg(size("r")?))
+    } else if raw == "m" {
+        Ok(OperandKind::M)
+    } else {
+        Err(format!("Unsupported operand type '{}'", op))
+    }
+}
+
+fn parse_opcode(opcode: &str) -> Result<(Vec<OpcodePart>, Option<u8>), String> {
+    let normalized = opcode.replace(" +r", "+r").replace('/', " /");
+
+    let mut parts = Vec::new();
+    let mut digit = None;
+
+    for part in normalized.split(' ').filter(|p| !p.is_empty()) {
+        let invalid = || format!("Invalid opcode part '{}'", part);
+
+        if let Some(d) = part.strip_prefix('/') {
+            digit = if d == "r" { None } else { Some(d.parse::<u8>().ok().filter(|d| *d < 8).ok_or_else(invalid)?) };
+        } else if part == "+" && matches!(parts.last(), Some(OpcodePart::Rex { .. })) {
+            // Like in `REX.W + 0F BE /r`
+            continue;
+        } else if part.starts_with("REX") {
+            // The W is sometimes written in lower case
+            parts.push(OpcodePart::Rex { wide: part.to_ascii_uppercase().ends_with(".W") });
+        } else if part.starts_with('i') {
+            parts.push(OpcodePart::Imm);
+        } else if part.starts_with('c') {
+            parts.push(OpcodePart::Rel);
+        } else if let Some((byte, _)) = part.split_once("+r") {
+            parts.push(OpcodePart::PlusReg(u8::from_str_radix(byte, 16).map_err(|_| invalid())?));
+        } else {
+            parts.push(OpcodePart::Byte(u8::from_str_radix(part, 16).map_err(|_| invalid())?));
+        }
+    }
+
+    Ok((parts, digit))
+}
+
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push(c),
+        }
+    }
+
+    fields
+}
+
+/// Parses the rows of an x86-csv file into templates. Rows that use encodings or operands the assembler doesn't
+/// support are skipped, exactly like the generator does.
+pub fn parse_template_csv(csv: &str) -> Result<Vec<InstructionTemplate>, String> {
+    let mut templates = Vec::new();
+
+    // The first line is the header
+    for (line, row) in csv.lines().enumerate().skip(1) {
+        let fields = split_csv_line(row);
+        if fields.len() < 2 {
+            continue;
+        }
+        let (instr, opcode) = (fields[0].as_str(), fields[1].as_str());
+        let feature = fields.get(5).map(|f| f.trim()).filter(|f| !f.is_empty());
+        let description = fields.get(11).map(|d| d.trim()).unwrap_or_default();
+
+        let valid_64 = fields.get(2).map(|v| v.trim());
+        // Like the one byte INC and DEC, which are REX prefixes in 64-bit mode
+        if opcode.contains("VEX") || matches!(valid_64, Some("Invalid" | "N.E.")) {
+            continue;
+        }
+        // NP only forbids the 66, F2 and F3 prefixes, which the templates don't add anyway
+        let opcode = opcode.trim_start_matches("NP ");
+        // A 16-bit push would be picked over PUSH imm32 for small values, but like in GNU as, it takes asking for it
+        if instr == "PUSH imm16" {
+            continue;
+        }
+
+        let mut sp = instr.split([' ', ',']).filter(|p| !p.is_empty()).collect::<Vec<&str>>();
+        if sp.is_empty() || sp.len() > 3 || sp[1..].iter().any(|op| UNSUPPORTED_OPERANDS.contains(op)) {
+            continue;
+        }
+        if sp.len() == 1 && ["CB", "CA iw"].contains(&opcode) {
+            sp[0] = "RETF";
+        }
+
+        let with_line = |e: String| format!("line {}: {}", line + 1, e);
+
+        let operands = sp[1..].iter().map(|op| parse_operand(op)).collect::<Result<Vec<_>, _>>().map_err(with_line)?;
+        let (mut opcode, digit) = parse_opcode(opcode).map_err(with_line)?;
+
+        // Immediates are always written after the opcode, so forms like ENTER imm16, 0 (C8 iw 00) can't be encoded
+        if opcode.iter().skip_while(|p| **p != OpcodePart::Imm).any(|p| matches!(p, OpcodePart::Byte(_))) {
+            continue;
+        }
+
+        let imm_operands = operands.iter().filter(|op| matches!(op, OperandKind::Imm(_))).count();
+        let imm_parts = opcode.iter().filter(|p| **p == OpcodePart::Imm).count();
+        let rel_operands = operands.iter().filter(|op| matches!(op, OperandKind::Rel(_))).count();
+        if imm_operands != imm_parts || imm_operands + rel_operands > 2 {
+            return Err(with_line(format!("Immediates of '{}' don't match its opcode", instr)));
+        }
+
+        let mnemonic = sp[0].to_lowercase();
+        let mut prefixes = if needs_size_override(&mnemonic, &operands) { vec![0x66] } else { Vec::new() };
+        while let [OpcodePart::Byte(byte), OpcodePart::Byte(_) | OpcodePart::Rex { .. } | OpcodePart::PlusReg(_), ..] =
+            opcode[..]
+        {
+            if !MANDATORY_PREFIXES.contains(&byte) {
+                break;
+            }
+            prefixes.push(byte);
+            opcode.remove(0);
+        }
+
+        templates.push(InstructionTemplate {
+            mnemonic: Box::leak(mnemonic.into_boxed_str()),
+            operands: leak(operands),
+            prefixes: leak(prefixes),
+            opcode: leak(opcode),
+            digit,
+            feature: feature.map(|f| &*Box::leak(f.to_string().into_boxed_str())),
+            description: Box::leak(description.to_string().into_boxed_str()),
+        });
+    }
+
+    Ok(templates)
+}
+
+pub fn matches_template(template: &InstructionTemplate, tokens: &[Token]) -> Result<Instruction, (usize, ParseError)> {
+    let mut iter = tokens.iter();
+
+    if !get_next(&mut iter)?.as_str().eq_ignore_ascii_case(template.mnemonic) {
+        return Err((iter.count(), ParseError::InvalidInstruction));
+    }
+
+    let mut rm = None;
+    let mut reg = None;
+    let mut imms = Vec::new();
+    let mut rel = None;
+
+    for (i, op) in template.operands.iter().enumerate() {
+        if i > 0 && get_next(&mut iter)? != "," {
+            return Err((iter.count(), ParseError::InvalidOperand));
+        }
+
+        match op {
+            OperandKind::Imm(size) => imms.push(is_imm_of_size(&mut iter, *size)?),
+            OperandKind::Rel(size) => rel = Some(is_rel_of_size(&mut iter, *size)?),
+            OperandKind::Specific(s) => {
+                let next = get_next(&mut iter)?;
+                if next.is_quoted() || !next.as_str().eq_ignore_ascii_case(s) {
+                    return Err((iter.count(), ParseError::InvalidOperand));
+                }
+            }
+            OperandKind::Reg(size) => reg = Some(is_reg_of_size(&mut iter, *size)?),
+            OperandKind::Rm(size) => rm = Some(is_rm_of_size(&mut iter, *size)?),
+            OperandKind::M => rm = Some(is_m_of_size(&mut iter, 0)?),
+        }
+    }
+
+    if iter.next().is_some() {
+        return Err((iter.count(), ParseError::ExtraneousTokenAfterInstruction));
+    }
+
+    let mut opcode = template.prefixes.iter().map(|p| Opcode::Prefix(*p)).collect::<Vec<Opcode>>();
+    let mut imm_fields = Vec::new();
+    let mut imms = imms.into_iter();
+
+    for part in template.opcode {
+        match part {
+            OpcodePart::Byte(byte) => opcode.push(Opcode::Byte(*byte)),
+            OpcodePart::PlusReg(byte) => opcode.push(Opcode::PlusReg(*byte, reg.unwrap_or(Register::Ax))),
+            OpcodePart::Rex { wide } => opcode.push(Opcode::Rex { wide: *wide }),
+            OpcodePart::Imm => imm_fields.push(imms.next()),
+            OpcodePart::Rel => imm_fields.push(rel.take()),
+        }
+    }
+    if rel.is_some() {
+        imm_fields.push(rel);
+    }
+    imm_fields.resize_with(2, || None);
+    let imm2 = imm_fields.pop().unwrap();
+    let imm1 = imm_fields.pop().unwrap();
+
+    let digit = template.digit.map(|d| Register::try_from(d as i32).unwrap());
+    let name = template.mnemonic.to_string();
+
+    let imms = Operands::immediates(imm1, imm2);
+    let instr = if let Some(rm) = rm {
+        let reg = reg.or(digit).unwrap_or(Register::Ax);
+        let r#mod = get_mod_from_rm(&rm);
+        let operands = Operands { r#mod, offset: rm.offset, reg: Some(reg), rm: Some(rm.reg), ..imms };
+        Instruction::new(name, opcode, operands).with_memory(rm.sib, rm.segment)
+    } else if let (Some(reg), Some(digit)) = (reg, digit) {
+        Instruction::new(name, opcode, Operands { reg: Some(digit), rm: Some(reg), ..imms })
+    } else {
+        Instruction::new(name, opcode, imms)
+    };
+
+    // Forms that sign extend an immediate, like `add eax, imm8`, only take it in the signed range
+    if !instr.immediates_fit() {
+        return Err((0, ParseError::InvalidOperand));
+    }
+
+    Ok(instr.with_rex(needs_rex(tokens)))
+}
+
+/// FNV-1a, has to stay in sync with `mnemonic_hash` in generate_instructions.py
+fn mnemonic_hash(mnemonic: &str) -> usize {
+    mnemonic.bytes().fold(0x811c9dc5u32, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x01000193)) as usize
+}
+
+/// All compiled in templates for a mnemonic
+pub fn lookup(mnemonic: &str) -> &'static [InstructionTemplate] {
+    let mut slot = mnemonic_hash(mnemonic) % MNEMONIC_INDEX.len();
+
+    while let Some((name, start, end)) = MNEMONIC_INDEX[slot] {
+        if name == mnemonic {
+            return &TEMPLATES[start..end];
+        }
+        slot = (slot + 1) % MNEMONIC_INDEX.len();
+    }
+
+    &[]
+}
+
+/// Matches an instruction against the compiled in templates
+pub fn matches(tokens: &[Token], features: &FeatureSet) -> Result<Instruction, (usize, ParseError)> {
+    let mnemonic = tokens[0].as_str().to_ascii_lowercase();
+    // Templates of other mnemonics would fail on the first token, which never beats the default error
+    let res = matches_templates(lookup(&mnemonic), tokens, features);
+
+    // GNU as (and therefore gcc) spells movsxd as movsx
+    if res.is_err() && mnemonic == "movsx" {
+        let mut renamed = tokens.to_vec();
+        renamed[0] = Token::new("movsxd");
+        if let Ok(instr) = matches_templates(lookup("movsxd"), &renamed, features) {
+            return Ok(instr);
+        }
+    }
+
+    res
+}
+
+/// Matches against all templates, picking the shortest encoding. Like GNU as, immediates that depend on symbols get
+/// 32 bits if there's a form for that
+pub fn matches_templates(
+    templates: &[InstructionTemplate],
+    tokens: &[Token],
+    features: &FeatureSet,
+) -> Result<Instruction, (usize, ParseError)> {
+    let mut best: Option<(Instruction, (bool, usize))> = None;
+    let mut err = (tokens.len() - 1, ParseError::InvalidInstruction);
+    let mut disabled = None;
+
+    for template in templates {
+        match matches_template(template, tokens) {
+            Ok(_) if template.feature.map(|f| !features.contains(f)).unwrap_or(false) => disabled = template.feature,
+            Ok(instr) => {
+                let instr = instr.with_feature(template.feature);
+                let key = (instr.narrow_reference(), instr.encoded_len());
+                if best.as_ref().map(|(_, k)| key < *k).unwrap_or(true) {
+                    best = Some((instr, key));
+                }
+            }
+            Err(e) => {
+                if e.0 < err.0 {
+                    err = e;
+                }
+            }
+        }
+    }
+
+    match (best, disabled) {
+        (Some((instr, _)), _) => Ok(instr),
+        (None, Some(feature)) => Err((tokens.len() - 1, ParseError::DisabledFeature(feature.to_string()))),
+        (None, None) => Err(err),
+    }
+}
@@ -6,8 +6,29 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::parse::encodings::matches;
+    use crate::parse::templates::{lookup, matches, OperandKind};
+    use crate::parse::expression::{evaluate, value, Value};
+    use crate::parse::features::FeatureSet;
+    use crate::parse::helpers::{Immediate, Relativity, Specifier};
+    use crate::parse::insn::parse_insn;
     use crate::parse::lexer::{Lexer, Token};
+    use crate::parse::nasm;
+    use crate::parse::parser::parse_lines;
+    use crate::parse::{
+        Alignment, DataValue, Directive, Line, ParseError, SectionAttributes, SymbolSize, SymbolType, SymbolValue,
+    };
+    use crate::target::X86_64;
+    use crate::instructions::{Encodable, EncodedInstruction, Instruction, Opcode, Operands, Size, Subtrahend};
+    use object::elf;
+
+    fn tokenize(line: &str) -> Vec<Token> {
+        let mut lexer = Lexer::new(line.to_string());
+        let mut tokens = Vec::new();
+        while let Ok(token) = lexer.read() {
+            tokens.push(token);
+        }
+        tokens
+    }
 
     #[test]
     fn test_lexer() {
@@ -26,21 +47,608 @@ mod tests {
         assert_eq!(lexer.read().unwrap().as_str(), "0x00");
         assert_eq!(lexer.read().unwrap().as_str(), ",");
         assert_eq!(lexer.read().unwrap().as_str(), "22");
+
+        // Strings end at their closing quote
+        let tokens = tokenize(".section \".note.GNU-stack\",\"\",@progbits");
+        let tokens = tokens.iter().map(|t| t.as_str()).collect::<Vec<&str>>();
+        assert_eq!(tokens, vec![".section", ".note.GNU-stack", ",", "", ",", "@progbits"]);
+
+        // Escaped quotes don't, and only the opening kind of quote closes a string
+        let tokens = tokenize(r#".ascii "a\"b", "it's""#);
+        let tokens = tokens.iter().map(|t| t.as_str()).collect::<Vec<&str>>();
+        assert_eq!(tokens, vec![".ascii", r#"a\"b"#, ",", "it's"]);
+
+        // `%` is the remainder after a value, and starts a name anywhere else
+        let tokens = tokenize("10%3 (x)%y %%top %1 (%rbp) %hi(s)");
+        let tokens = tokens.iter().map(|t| t.as_str()).collect::<Vec<&str>>();
+        let expected = ["10", "%", "3", "(", "x", ")", "%", "y", "%%top", "%1", "(", "%rbp", ")", "%hi", "(", "s", ")"];
+        assert_eq!(tokens, expected);
     }
 
     #[test]
     fn test_assembler() {
-        let instr = matches(&vec![Token::new("push"), Token::new("rbp")]);
+        let instr = matches(&vec![Token::new("push"), Token::new("rbp")], &FeatureSet::default());
 
         assert!(instr.is_ok());
         let instr = instr.unwrap();
-        assert_eq!(instr.encode().get_bytes(), &vec![0x55u8]);
+        assert_eq!(instr.encode().unwrap().get_bytes(), &vec![0x55u8]);
 
-        let instr = matches(&vec![Token::new("rdseed"), Token::new("eax")]);
+        let instr = matches(&vec![Token::new("rdseed"), Token::new("eax")], &FeatureSet::default());
 
         assert!(instr.is_ok());
         let instr = instr.unwrap();
-        assert_eq!(instr.encode().get_bytes(), &vec![0x0F, 0xC7, 0xF8]);
+        assert_eq!(instr.encode().unwrap().get_bytes(), &vec![0x0F, 0xC7, 0xF8]);
     }
-}
 
+    #[test]
+    fn test_matcher() {
+        assert!(lookup("mov").iter().all(|t| t.mnemonic == "mov"));
+        assert!(!lookup("mov").is_empty());
+        assert!(lookup("movx").is_empty());
+
+        // The shortest encoding wins, and its size is known without encoding it
+        for (line, len) in [("add rax, 1", 4), ("add rax, 1000", 6), ("mov qword ptr [rsp + 8], rbx", 5)] {
+            let instr = matches(&tokenize(line), &FeatureSet::default()).unwrap();
+            assert_eq!(instr.encoded_len(), len);
+            assert_eq!(instr.encode().unwrap().get_bytes().len(), len);
+        }
+
+        let imul = lookup("imul").iter().map(|t| (t.to_string(), t.encoding())).collect::<Vec<(String, String)>>();
+        assert!(imul.contains(&("imul r16, r/m16".to_string(), "66 0F AF /r".to_string())));
+        assert!(imul.contains(&("imul r/m64".to_string(), "REX.W F7 /5".to_string())));
+        let popcnt = lookup("popcnt").iter().find(|t| t.operands[0] == OperandKind::Reg(64)).unwrap();
+        assert_eq!(popcnt.prefixes, &[0xF3]);
+        assert_eq!(popcnt.feature, Some("POPCNT"));
+
+        // 16-bit forms get the operand size override
+        let add = matches(&tokenize("add cx, 1"), &FeatureSet::default()).unwrap();
+        assert_eq!(add.encode().unwrap().get_bytes(), &vec![0x66, 0x83, 0xC1, 0x01]);
+
+        let err = matches(&tokenize("add rax, rbx, rcx"), &FeatureSet::default());
+        assert!(matches!(err, Err((1, ParseError::ExtraneousTokenAfterInstruction))));
+        let err = matches(&tokenize("movx rax, rbx"), &FeatureSet::default());
+        assert!(matches!(err, Err((3, ParseError::InvalidInstruction))));
+    }
+
+    #[test]
+    fn test_gcc_syntax() {
+        let encode = |line: &str| {
+            let encoded = matches(&tokenize(line), &FeatureSet::default()).unwrap().encode().unwrap();
+            encoded.get_bytes().clone()
+        };
+
+        assert_eq!(encode("mov QWORD PTR -24[rbp], rdi"), vec![0x48, 0x89, 0x7D, 0xE8]);
+        assert_eq!(encode("MOV EAX, DWORD PTR [RBX]"), vec![0x8B, 0x03]);
+        assert_eq!(encode("lea rdx, 0[0+rax*4]"), vec![0x48, 0x8D, 0x14, 0x85, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(encode("movzx eax, BYTE PTR [rdx+rax]"), vec![0x0F, 0xB6, 0x04, 0x02]);
+        assert_eq!(encode("mov eax, DWORD PTR [rbp+r12*8+16]"), vec![0x42, 0x8B, 0x44, 0xE5, 0x10]);
+        assert_eq!(encode("mov rax, QWORD PTR fs:40"), vec![0x64, 0x48, 0x8B, 0x04, 0x25, 0x28, 0x00, 0x00, 0x00]);
+        assert_eq!(encode("movsx rsi, esi"), vec![0x48, 0x63, 0xF6]);
+        assert_eq!(encode("push r12"), vec![0x41, 0x54]);
+        assert_eq!(encode("mov sil, 1"), vec![0x40, 0xB6, 0x01]);
+        assert_eq!(encode("mov ah, 1"), vec![0xB4, 0x01]);
+
+        let counter = matches(&tokenize("add DWORD PTR counter[rip], 1"), &FeatureSet::default()).unwrap();
+        let counter = counter.encode().unwrap();
+        assert_eq!(counter.get_bytes(), &vec![0x83, 0x05, 0x00, 0x00, 0x00, 0x00, 0x01]);
+        assert_eq!(counter.get_refs()[0].at, 2);
+
+        let offset = matches(&tokenize("mov edi, OFFSET FLAT:.LC0"), &FeatureSet::default()).unwrap().encode().unwrap();
+        assert_eq!(offset.get_bytes()[0], 0xBF);
+        assert_eq!(offset.get_refs()[0].to, ".LC0");
+
+        let err = matches(&tokenize("mov rax, QWORD PTR [rip+rax*2]"), &FeatureSet::default());
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_gcc_directives() {
+        let code = "\t.text\n.LC0:\n\t.string \"hi\"\n\t.section .data.rel.local,\"aw\"\n\t.section .note.GNU-stack,\"\",@progbits\n";
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+
+        assert!(matches!(&lines[0], Line::Directive(Directive::Section(name, None)) if name == ".text"));
+        assert!(matches!(&lines[1], Line::Label(name) if name == ".LC0"));
+        assert!(matches!(&lines[2], Line::Directive(Directive::Bytes(bytes)) if bytes == b"hi\0"));
+        let data = SectionAttributes { flags: (elf::SHF_ALLOC | elf::SHF_WRITE) as u64, typ: None, entsize: 0 };
+        assert!(matches!(&lines[3], Line::Directive(Directive::Section(name, Some(attributes)))
+            if name == ".data.rel.local" && *attributes == data));
+        let note = SectionAttributes { flags: 0, typ: Some(elf::SHT_PROGBITS), entsize: 0 };
+        assert!(matches!(&lines[4], Line::Directive(Directive::Section(name, Some(attributes)))
+            if name == ".note.GNU-stack" && *attributes == note));
+
+        let code = ".section .rodata.str1.1,\"aMS\",@progbits,1\n.section .tdata,\"awT\",@nobits\n";
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+        let flags = (elf::SHF_ALLOC | elf::SHF_MERGE | elf::SHF_STRINGS) as u64;
+        let strings = SectionAttributes { flags, typ: Some(elf::SHT_PROGBITS), entsize: 1 };
+        assert!(matches!(&lines[0], Line::Directive(Directive::Section(_, Some(a))) if *a == strings));
+        let flags = (elf::SHF_ALLOC | elf::SHF_WRITE | elf::SHF_TLS) as u64;
+        let tls = SectionAttributes { flags, typ: Some(elf::SHT_NOBITS), entsize: 0 };
+        assert!(matches!(&lines[1], Line::Directive(Directive::Section(_, Some(a))) if *a == tls));
+
+        let parse = |code: &str| parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse(".section .data,aw\n").is_err());
+        assert!(parse(".section .data,\"awq\"\n").is_err());
+        assert!(parse(".section .data,\"aw\",@things\n").is_err());
+        assert!(parse(".section .rodata.cst8,\"aM\",@progbits\n").is_err());
+    }
+
+    #[test]
+    fn test_clang_directives() {
+        let code = ".LBB0_1:\n.Ltmp0:\n\t.type\tmain,@function\n\t.size\tmain, .Lfunc_end0-main\n\t.size\t.L.str, 16\n\t.section\t\".note.GNU-stack\",\"\",@progbits\n\t.p2align\t4, 0x90\n\t.addrsig\n";
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+
+        assert!(matches!(&lines[0], Line::Label(name) if name == ".LBB0_1"));
+        assert!(matches!(&lines[1], Line::Label(name) if name == ".Ltmp0"));
+        assert!(matches!(&lines[2], Line::Directive(Directive::Type(name, SymbolType::Function)) if name == "main"));
+        assert!(matches!(&lines[3], Line::Directive(Directive::Size(name, SymbolSize::Difference(end, start)))
+            if name == "main" && end == ".Lfunc_end0" && start == "main"));
+        assert!(matches!(&lines[4], Line::Directive(Directive::Size(_, SymbolSize::Constant(16)))));
+        assert!(matches!(&lines[5], Line::Directive(Directive::Section(name, _)) if name == ".note.GNU-stack"));
+
+        assert!(parse_lines("test.s".to_string(), ".size main, 1 2\n".to_string(), &mut X86_64::default()).is_err());
+    }
+
+    #[test]
+    fn test_data_directives() {
+        let code = ".LJTI0_0:\n\t.long\t.LBB0_3-.LJTI0_0, 5, -1\n\t.quad sym - .\n";
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+
+        let difference = |to: &str, minus: &str| {
+            let (symbol, minus) = (Some(to.to_string()), Some(minus.to_string()));
+            DataValue::Reference(Value { symbol, minus, ..Value::default() })
+        };
+        let values = vec![difference(".LBB0_3", ".LJTI0_0"), DataValue::Integer(5), DataValue::Integer(-1)];
+        assert!(matches!(&lines[1], Line::Directive(Directive::Data(4, v)) if *v == values));
+        let values = vec![difference("sym", ".")];
+        assert!(matches!(&lines[2], Line::Directive(Directive::Data(8, v)) if *v == values));
+
+        let code = ".byte 1, -1\n.short 2\n.word 3\n.int .LC0\n.quad 0xffffffffffffffff\n.octa 1\n";
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+        let sizes = lines
+            .iter()
+            .filter_map(|l| if let Line::Directive(Directive::Data(size, _)) = l { Some(*size) } else { None })
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, vec![1, 2, 2, 4, 8, 16]);
+        let symbol = DataValue::Reference(Value { symbol: Some(".LC0".to_string()), ..Value::default() });
+        assert!(matches!(&lines[3], Line::Directive(Directive::Data(4, v)) if v[0] == symbol));
+        assert!(matches!(&lines[4], Line::Directive(Directive::Data(8, v)) if v[0] == DataValue::Integer(u64::MAX as i128)));
+
+        let parse = |code: &str| parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse(".octa sym\n").is_err());
+        assert!(parse(".long\n").is_err());
+        assert!(parse(".long 1,\n").is_err());
+        assert!(parse(".quad a * 2\n").is_err());
+        assert!(parse(".quad -a\n").is_err());
+    }
+
+    #[test]
+    fn test_string_directives() {
+        let bytes = |code: &str| {
+            let lines = parse_lines("test.s".to_string(), format!("{}\n", code), &mut X86_64::default()).unwrap();
+            match &lines[0] {
+                Line::Directive(Directive::Bytes(bytes)) => bytes.clone(),
+                _ => panic!("{} isn't data", code),
+            }
+        };
+
+        assert_eq!(bytes(r#".ascii "hi\n""#), b"hi\n");
+        assert_eq!(bytes(r#".asciz "a", "b""#), b"a\0b\0");
+        assert_eq!(bytes(r#".string "\101\0\1234\x414\\\"\q""#), b"A\0S4\x14\\\"q\0");
+        assert_eq!(bytes(r#".string "\303\251""#), vec![0xC3, 0xA9, 0]);
+        assert_eq!(bytes(r#".string16 "A\xff""#), vec![0x41, 0, 0xFF, 0, 0, 0]);
+        assert_eq!(bytes(r#".string32 "A""#), vec![0x41, 0, 0, 0, 0, 0, 0, 0]);
+
+        let parse = |code: &str| parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse(".ascii\n").is_err());
+        assert!(parse(".ascii \"a\",\n").is_err());
+        assert!(parse(".asciz hi\n").is_err());
+    }
+
+    #[test]
+    fn test_float_directives() {
+        let bytes = |code: &str| {
+            let lines = parse_lines("test.s".to_string(), format!("{}\n", code), &mut X86_64::default()).unwrap();
+            match &lines[0] {
+                Line::Directive(Directive::Bytes(bytes)) => bytes.clone(),
+                _ => panic!("{} isn't data", code),
+            }
+        };
+
+        assert_eq!(bytes(".float 1.5, -1.5e-3"), vec![0, 0, 0xC0, 0x3F, 0xA6, 0x9B, 0xC4, 0xBA]);
+        assert_eq!(bytes(".single inf, -nan"), vec![0, 0, 0x80, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(bytes(".double 3.14159"), vec![0x6E, 0x86, 0x1B, 0xF0, 0xF9, 0x21, 0x09, 0x40]);
+        assert_eq!(bytes(".double 0x1.8p3"), 12f64.to_le_bytes());
+        assert_eq!(bytes(".tfloat 3.14159"), vec![0x1D, 0x72, 0x33, 0xDC, 0x80, 0xCF, 0x0F, 0xC9, 0x00, 0x40]);
+        assert_eq!(bytes(".tfloat -inf"), vec![0, 0, 0, 0, 0, 0, 0, 0x80, 0xFF, 0xFF]);
+        // Denormals and ties, which round to even
+        assert_eq!(bytes(".float 1e-40"), vec![0xC2, 0x16, 0x01, 0x00]);
+        assert_eq!(bytes(".float 16777217"), 16777216f32.to_le_bytes());
+
+        let parse = |code: &str| parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse(".float\n").is_err());
+        assert!(parse(".float 1e39\n").is_err());
+        assert!(parse(".double 1.5x\n").is_err());
+    }
+
+    #[test]
+    fn test_alignment_directives() {
+        let code = ".p2align 4, 0x90\n.p2align 4,,10\n.balign 8\n.align 16, 0, 3\n.nops 3\n.nops 2 * 2, 2\n";
+        let code = format!("{}.p2align 1 + 3\n", code);
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+
+        assert!(matches!(&lines[0], Line::Directive(Directive::Align(Alignment::PowerOfTwo(4), Some(0x90), None))));
+        assert!(matches!(&lines[1], Line::Directive(Directive::Align(Alignment::PowerOfTwo(4), None, Some(10)))));
+        assert!(matches!(&lines[2], Line::Directive(Directive::Align(Alignment::Bytes(8), None, None))));
+        assert!(matches!(&lines[3], Line::Directive(Directive::Align(Alignment::Either(16), Some(0), Some(3)))));
+        assert!(matches!(&lines[4], Line::Directive(Directive::Bytes(bytes)) if bytes == &[0x0F, 0x1F, 0x00]));
+        assert!(matches!(&lines[5], Line::Directive(Directive::Bytes(bytes)) if bytes == &[0x66, 0x90, 0x66, 0x90]));
+        assert!(matches!(&lines[6], Line::Directive(Directive::Align(Alignment::PowerOfTwo(4), None, None))));
+
+        assert_eq!(Alignment::Either(4).bytes(true), Some(4));
+        assert_eq!(Alignment::Either(4).bytes(false), Some(16));
+        assert_eq!(Alignment::Either(3).bytes(true), None);
+
+        let parse = |code: &str| parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse(".balign 3\n").is_err());
+        assert!(parse(".p2align\n").is_err());
+        assert!(parse(".p2align 4, 0, 1, 2\n").is_err());
+        assert!(parse(".nops 3, 12\n").is_err());
+    }
+
+    #[test]
+    fn test_space_directives() {
+        let code = ".zero 64\n.space 2 * (1 + 2), 0x1ff\n.skip 2\n.fill 2, 3, 0xaabbccdd\n.fill 1, 12, -1\n.org 510\n";
+        let code = format!("{}.org . + 4, 0x90\n", code);
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+
+        assert!(matches!(&lines[0], Line::Directive(Directive::Space(64))));
+        assert!(matches!(&lines[1], Line::Directive(Directive::Bytes(bytes)) if bytes == &[0xFF; 6]));
+        assert!(matches!(&lines[2], Line::Directive(Directive::Space(2))));
+        let fill = [0xDD, 0xCC, 0xBB, 0xDD, 0xCC, 0xBB];
+        assert!(matches!(&lines[3], Line::Directive(Directive::Bytes(bytes)) if bytes == &fill));
+        // The size is at most 8, and only 4 bytes of the value are used
+        let fill = [0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0];
+        assert!(matches!(&lines[4], Line::Directive(Directive::Bytes(bytes)) if bytes == &fill));
+        let location = Value::constant(0x1FE);
+        assert!(matches!(&lines[5], Line::Directive(Directive::Org(org, 0)) if org == &location));
+        let here = Value { symbol: Some(".".to_string()), addend: 4, ..Value::default() };
+        assert!(matches!(&lines[6], Line::Directive(Directive::Org(location, 0x90)) if location == &here));
+
+        let parse = |code: &str| parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse(".zero\n").is_err());
+        assert!(parse(".zero 1, 2\n").is_err());
+        assert!(parse(".space -1\n").is_err());
+        assert!(parse(".space (1\n").is_err());
+        assert!(parse(".fill 1, 2, 3, 4\n").is_err());
+        assert!(parse(".org sym\n").is_err());
+        assert!(parse(".org -1\n").is_err());
+    }
+
+    #[test]
+    fn test_expressions() {
+        let evaluate = |code: &str| evaluate(&tokenize(code), 0).ok();
+        assert_eq!(evaluate("1 + 2 * 3 - -1"), Some(8));
+        // Like in GNU as, | binds tighter than +, and true comparisons are -1
+        assert_eq!(evaluate("1 | 2 + 1"), Some(4));
+        assert_eq!(evaluate("(1 << 4) >> 2 | 7 % 4"), Some(7));
+        assert_eq!(evaluate("10%3"), Some(1));
+        assert_eq!(evaluate("3 < 4"), Some(-1));
+        assert_eq!(evaluate("~0 + !0 + !5"), Some(0));
+        assert_eq!(evaluate("2 == 2 && 1 != 1"), Some(0));
+        assert_eq!(evaluate("0xffffffffffffffff"), Some(-1));
+        assert!(evaluate("1 / 0").is_none());
+        assert!(evaluate("(1 + 2").is_none());
+        assert!(evaluate("foo").is_none());
+
+        let value = |code: &str| value(&tokenize(code), 0).ok();
+        let symbol = |name: &str, minus: Option<&str>, addend| Value {
+            symbol: Some(name.to_string()),
+            minus: minus.map(|m| m.to_string()),
+            addend,
+            ..Value::default()
+        };
+        assert_eq!(value("foo + 16 - 4"), Some(symbol("foo", None, 12)));
+        assert_eq!(value("end - start + 2 * 4"), Some(symbol("end", Some("start"), 8)));
+        assert_eq!(value("-(start - end)"), Some(symbol("end", Some("start"), 0)));
+        assert_eq!(value(". - 2"), Some(symbol(".", None, -2)));
+        assert_eq!(value("a - a + 1"), Some(Value::constant(1)));
+        assert_eq!(value("puts@PLT + 4").unwrap().specifier, Some(Specifier::Plt));
+        assert!(value("a + b").is_none());
+        assert!(value("2 * foo").is_none());
+        assert!(value("0 - puts@PLT").is_none());
+
+        let encode = |line: &str| matches(&tokenize(line), &FeatureSet::default()).unwrap().encode().unwrap();
+        assert_eq!(encode("mov rax, [rbp + 8*2 - 1]").get_bytes(), &vec![0x48, 0x8B, 0x45, 0x0F]);
+        assert_eq!(encode("mov eax, [rbx + (8 - 2) * 4]").get_bytes(), &vec![0x8B, 0x43, 0x18]);
+        assert_eq!(encode("add eax, 1 << 4 | 1").get_bytes(), &vec![0x83, 0xC0, 0x11]);
+        assert_eq!(encode("add al, -128").get_bytes(), &vec![0x04, 0x80]);
+        assert!(matches(&tokenize("add al, -129"), &FeatureSet::default()).is_err());
+        let mut encoded = EncodedInstruction::new("add".to_string());
+        let byte = |value: i64| Immediate::integer(value, Relativity::Absolute, Size::Byte);
+        assert!(encoded.write_immediate(&byte(200), false).is_ok());
+        assert!(encoded.write_immediate(&byte(200), true).is_err());
+        assert!(encoded.write_immediate(&byte(256), false).is_err());
+        assert_eq!(encoded.get_bytes(), &vec![0xC8]);
+        // Instructions that weren't parsed aren't range checked, encoding them fails instead
+        let operands = Operands::immediates(Some(byte(256)), None);
+        let add = Instruction::new("add".to_string(), vec![Opcode::Byte(0x04)], operands);
+        assert!(add.encode().is_err());
+        let call = encode("call foo+16");
+        assert_eq!((call.get_refs()[0].to.as_str(), call.get_refs()[0].addend), ("foo", 16));
+        let difference = encode("mov eax, end - start");
+        assert_eq!(difference.get_refs()[0].minus, Some(Subtrahend::Symbol("start".to_string())));
+        // Symbols get 32 bits, even if there's a shorter form
+        assert_eq!(encode("add rax, end - start").get_bytes().len(), 6);
+        assert!(matches(&tokenize("mov eax, ebx + 1"), &FeatureSet::default()).is_err());
+        assert!(matches(&tokenize("mov eax, [rbx - foo]"), &FeatureSet::default()).is_err());
+    }
+
+    #[test]
+    fn test_zerofill_directives() {
+        let code = ".lcomm buf, 4096\n.lcomm small, 3\n.lcomm aligned, 8, 64\n.zerofill __DATA,__bss,_buf,64,4\n\
+                    .zerofill __DATA,__common\n";
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+
+        let zerofill = |line: &Line| match line {
+            Line::Directive(Directive::Zerofill { section, symbol }) => (section.clone(), symbol.clone()),
+            _ => panic!("{:?} isn't .zerofill", line),
+        };
+        // Without an alignment, it's the largest power of two up to 8 that fits in the size
+        assert_eq!(zerofill(&lines[0]), (None, Some(("buf".to_string(), 4096, Alignment::Bytes(8)))));
+        assert_eq!(zerofill(&lines[1]), (None, Some(("small".to_string(), 3, Alignment::Bytes(2)))));
+        assert_eq!(zerofill(&lines[2]), (None, Some(("aligned".to_string(), 8, Alignment::Either(64)))));
+        let symbol = Some(("_buf".to_string(), 64, Alignment::PowerOfTwo(4)));
+        assert_eq!(zerofill(&lines[3]), (Some("__DATA,__bss".to_string()), symbol));
+        assert_eq!(zerofill(&lines[4]), (Some("__DATA,__common".to_string()), None));
+
+        let parse = |code: &str| parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse(".lcomm buf\n").is_err());
+        assert!(parse(".lcomm buf, -1\n").is_err());
+        assert!(parse(".lcomm buf, 1, 2, 3\n").is_err());
+        assert!(parse(".zerofill __DATA\n").is_err());
+        assert!(parse(".zerofill __DATA,__bss,_buf\n").is_err());
+
+        let lines = parse(".local x\n.comm x,4,4\n.comm y,32\n").unwrap();
+        assert!(matches!(&lines[0], Line::Directive(Directive::Local(name)) if name == "x"));
+        assert!(matches!(&lines[1], Line::Directive(Directive::Common(name, 4, Alignment::Either(4))) if name == "x"));
+        assert!(matches!(&lines[2], Line::Directive(Directive::Common(name, 32, Alignment::Bytes(8))) if name == "y"));
+        assert!(parse(".comm x\n").is_err());
+    }
+
+    #[test]
+    fn test_symbol_directives() {
+        let code = ".equ BUFSIZE, 4096\nSYS_write=1\n.set NEG, -(4 * 2)\n.set alias, main\n.equiv SIZE, BUFSIZE * 2\n\
+                    mov eax, BUFSIZE\nmov rax, qword ptr [rbp + NEG]\n.long SYS_write, NEG\n.space SIZE\n";
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+
+        let set = |line: &Line| match line {
+            Line::Directive(Directive::Set(name, value)) => (name.clone(), value.clone()),
+            _ => panic!("{:?} isn't .set", line),
+        };
+        assert_eq!(set(&lines[0]), ("BUFSIZE".to_string(), SymbolValue::Constant(4096)));
+        assert_eq!(set(&lines[1]), ("SYS_write".to_string(), SymbolValue::Constant(1)));
+        assert_eq!(set(&lines[2]), ("NEG".to_string(), SymbolValue::Constant(-8)));
+        assert_eq!(set(&lines[3]), ("alias".to_string(), SymbolValue::Symbol("main".to_string())));
+        assert_eq!(set(&lines[4]), ("SIZE".to_string(), SymbolValue::Constant(8192)));
+
+        // Constants defined before are substituted, so they're encoded like numbers
+        let bytes = |line: &Line| match line {
+            Line::Instruction(instruction) => instruction.encode().unwrap().get_bytes().clone(),
+            _ => panic!("{:?} isn't an instruction", line),
+        };
+        assert_eq!(bytes(&lines[5]), vec![0xB8, 0x00, 0x10, 0x00, 0x00]);
+        assert_eq!(bytes(&lines[6]), vec![0x48, 0x8B, 0x45, 0xF8]);
+        let values = vec![DataValue::Integer(1), DataValue::Integer(-8)];
+        assert!(matches!(&lines[7], Line::Directive(Directive::Data(4, v)) if *v == values));
+        assert!(matches!(&lines[8], Line::Directive(Directive::Space(8192))));
+
+        // Constants defined after their use are written later, in the range the CPU reads the immediate in
+        let signed = |code: &str| {
+            match &parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap()[0] {
+                Line::Instruction(instruction) => instruction.encode().unwrap().get_refs()[0].signed,
+                line => panic!("{:?} isn't an instruction", line),
+            }
+        };
+        assert!(!signed("add al, D\n.equ D, 200\n"));
+        assert!(!signed("mov eax, D\n.equ D, 0xffffffff\n"));
+        assert!(signed("add rax, D\n.equ D, 8\n"));
+
+        let parse = |code: &str| parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse(".equ X, 1\n.equiv X, 2\n").is_err());
+        assert!(parse("main:\n.eqv main, 2\n").is_err());
+        assert!(parse(".set X, 1\n.set X, 2\n").is_ok());
+        assert!(parse(".equ X\n").is_err());
+        assert!(parse(".set X, a + b\n").is_err());
+    }
+
+    #[test]
+    fn test_conditional_directives() {
+        let code = ".set SIZE, 16384\n.ifdef SIZE\n.byte 1\n.else\n.byte 2\n.endif\n\
+                    .if SIZE > 4096\n.if SIZE == 8192\n.byte 3\n.elseif SIZE == 16384\n.byte 4\n\
+                    .else\n.byte 5\n.endif\n\
+                    .elseif 1\n.byte 6\n.endif\n.ifndef SIZE\n.byte 7\n.endif\n.ifc a,a\n.byte 8\n.endif\n\
+                    .ifeqs \"a\", \"b\"\n.byte 9\n.endif\n.if 0\n.if garbage +\n.endif\n.error \"skipped\"\n.endif\n\
+                    .warning \"careful\"\n.print \"hello\"\n";
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+        let bytes = lines
+            .iter()
+            .filter_map(|l| match l {
+                Line::Directive(Directive::Data(1, v)) => Some(v[0].clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(bytes, vec![DataValue::Integer(1), DataValue::Integer(4), DataValue::Integer(8)]);
+
+        let parse = |code: &str| parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse(".error \"unsupported\"\n").is_err());
+        assert!(parse(".if 1\n").is_err());
+        assert!(parse(".endif\n").is_err());
+        assert!(parse(".if 1\n.else\n.else\n.endif\n").is_err());
+        assert!(parse(".if 1\n.else\n.elseif 1\n.endif\n").is_err());
+        assert!(parse(".ifdef\n.endif\n").is_err());
+        assert!(parse(".ifc a\n.endif\n").is_err());
+        assert!(parse(".if undefined\n.endif\n").is_err());
+    }
+
+    #[test]
+    fn test_syntax_directives() {
+        let code = ".att_syntax\nmovq %rax, %rbx\n.intel_syntax noprefix\nmov rbx, rax\n";
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+
+        let bytes = lines
+            .iter()
+            .filter_map(|l| if let Line::Instruction(i) = l { Some(i.encode().unwrap()) } else { None })
+            .map(|encoded| encoded.get_bytes().clone())
+            .collect::<Vec<Vec<u8>>>();
+        assert_eq!(bytes, vec![vec![0x48, 0x89, 0xC3], vec![0x48, 0x89, 0xC3]]);
+
+        // With prefixes, bare register names are symbols
+        let code = ".intel_syntax prefix\nmov %rbx, %rax\nmov %rax, qword ptr [%rip + rax]\npush %fs\n";
+        let lines = parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+
+        let encoded = lines
+            .iter()
+            .filter_map(|l| if let Line::Instruction(i) = l { Some(i.encode().unwrap()) } else { None })
+            .collect::<Vec<_>>();
+        assert_eq!(encoded[0].get_bytes(), &vec![0x48, 0x89, 0xC3]);
+        assert_eq!(encoded[1].get_bytes(), &vec![0x48, 0x8B, 0x05, 0, 0, 0, 0]);
+        assert_eq!(encoded[1].get_refs()[0].to, "rax");
+        assert_eq!(encoded[2].get_bytes(), &vec![0x0F, 0xA0]);
+
+        let parse = |code: &str| parse_lines("test.s".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse(".intel_syntax\nmov rbx, rax\n").is_ok());
+        assert!(parse(".intel_syntax\nmov %rbx, %rax\n").is_err());
+        assert!(parse(".intel_syntax prefix\nmov %rbx, %foo\n").is_err());
+        assert!(parse(".intel_syntax noprefix\nmov rbx, rax\n").is_ok());
+        assert!(parse(".intel_syntax nopefix\n").is_err());
+        assert!(parse(".att_syntax noprefix\n").is_err());
+
+        // A directive that fails doesn't switch the syntax
+        let mut target = X86_64::default();
+        let code = ".att_syntax noprefix\nmov rbx, rax\n".to_string();
+        assert!(parse_lines("test.s".to_string(), code, &mut target).is_err());
+        let code = "mov rbx, rax\n".to_string();
+        assert!(parse_lines("test.s".to_string(), code, &mut target).is_ok());
+    }
+
+    #[test]
+    fn test_nasm() {
+        let code = "%define COUNT 2\n%macro twice 1\n  add eax, %1\n  add eax, %1\n%endmacro\n\nsection .data\nmsg db 'hi', 10 ; greeting\nlen equ $ - msg\nnums dw 10h, 0b11\n     times COUNT db 0\nsection .bss\nbuf resq 2\n\nsection .text\ndefault rel\nglobal start:function\nstart:\n  lea rsi, [msg]\n  mov edx, len\n  mov dword [abs 8], 1\n.loop:\n  twice COUNT\n  jmp .loop\n";
+        let lines = nasm::parse_lines("test.asm".to_string(), code.to_string(), &mut X86_64::default()).unwrap();
+
+        assert!(matches!(&lines[1], Line::Directive(Directive::Section(name, None)) if name == ".data"));
+        assert!(matches!(&lines[2], Line::Label(name) if name == "msg"));
+        assert!(matches!(&lines[3], Line::Directive(Directive::Bytes(bytes)) if bytes == b"hi\n"));
+        assert!(matches!(&lines[4], Line::Directive(Directive::Set(name, SymbolValue::Constant(3))) if name == "len"));
+        assert!(matches!(&lines[6], Line::Directive(Directive::Bytes(bytes)) if bytes == &[0x10, 0, 3, 0]));
+        assert!(matches!(&lines[7], Line::Directive(Directive::Bytes(bytes)) if bytes == &[0]));
+        assert!(matches!(&lines[8], Line::Directive(Directive::Bytes(bytes)) if bytes == &[0]));
+        assert!(matches!(&lines[11], Line::Directive(Directive::Space(16))));
+        assert!(matches!(&lines[13], Line::Directive(Directive::Type(name, SymbolType::Function)) if name == "start"));
+        assert!(matches!(&lines[19], Line::Label(name) if name == "start.loop"));
+
+        let bytes = lines
+            .iter()
+            .filter_map(|l| if let Line::Instruction(i) = l { Some(i.encode().unwrap()) } else { None })
+            .map(|encoded| encoded.get_bytes().clone())
+            .collect::<Vec<Vec<u8>>>();
+        assert_eq!(bytes[0], vec![0x48, 0x8D, 0x35, 0, 0, 0, 0]);
+        assert_eq!(bytes[1], vec![0xBA, 3, 0, 0, 0]);
+        assert_eq!(bytes[2], vec![0xC7, 0x04, 0x25, 8, 0, 0, 0, 1, 0, 0, 0]);
+        assert_eq!(bytes[3], vec![0x83, 0xC0, 2]);
+        assert_eq!(bytes[4], vec![0x83, 0xC0, 2]);
+
+        let parse = |code: &str| nasm::parse_lines("test.asm".to_string(), code.to_string(), &mut X86_64::default());
+        assert!(parse("bits 32\n").is_err());
+        assert!(parse("x equ later + 1\n").is_err());
+        assert!(parse("%macro m 1\nret\n").is_err());
+        assert!(parse("call puts wrt ..foo\n").is_err());
+        // The labels made for `$` and macros contain an @, which isn't a relocation specifier
+        assert!(parse("jmp $\n").is_ok());
+        assert!(parse("%macro skip 0\njmp %%over\n%%over:\n%endmacro\nskip\n").is_ok());
+
+        // Backslashes aren't escapes in NASM
+        let lines = parse("db 'C:\\', 0\n").unwrap();
+        assert!(matches!(&lines[1], Line::Directive(Directive::Bytes(bytes)) if bytes == b"C:\\\0"));
+
+        // Addresses of labels in data are left to the assembler, between the bytes of constants
+        let lines = parse("table dq main, table + 8\ndb 1, 2\ndd 3, .end - table\n.end:\n").unwrap();
+        let reference = |line: &Line| match line {
+            Line::Directive(Directive::Data(size, values)) => match &values[..] {
+                [DataValue::Reference(value)] => (*size, value.clone()),
+                _ => panic!("{:?} isn't a single reference", values),
+            },
+            _ => panic!("{:?} isn't data", line),
+        };
+        let symbol = |name: &str, minus: Option<&str>, addend| Value {
+            symbol: Some(name.to_string()),
+            minus: minus.map(|m| m.to_string()),
+            addend,
+            ..Value::default()
+        };
+        assert_eq!(reference(&lines[2]), (8, symbol("main", None, 0)));
+        assert_eq!(reference(&lines[3]), (8, symbol("table", None, 8)));
+        assert!(matches!(&lines[4], Line::Directive(Directive::Bytes(bytes)) if bytes == &[1, 2]));
+        assert!(matches!(&lines[5], Line::Directive(Directive::Bytes(bytes)) if bytes == &[3, 0, 0, 0]));
+        assert_eq!(reference(&lines[6]), (4, symbol("table.end", Some("table"), 0)));
+
+        // Constants used before their equ are symbols, which the assembler fills in
+        let lines = parse("mov eax, SIZE\nSIZE equ 4 * 4\nentry equ start\n").unwrap();
+        assert!(matches!(&lines[1], Line::Instruction(i) if i.encode().unwrap().get_refs()[0].to == "SIZE"));
+        let set = |line: &Line| match line {
+            Line::Directive(Directive::Set(name, value)) => (name.clone(), value.clone()),
+            _ => panic!("{:?} isn't equ", line),
+        };
+        assert_eq!(set(&lines[2]), ("SIZE".to_string(), SymbolValue::Constant(16)));
+        assert_eq!(set(&lines[3]), ("entry".to_string(), SymbolValue::Symbol("start".to_string())));
+
+        let lines = parse("extern puts\ncall puts wrt ..plt\n").unwrap();
+        let call = lines.iter().find_map(|l| if let Line::Instruction(i) = l { i.encode().ok() } else { None });
+        assert_eq!(call.unwrap().get_refs()[0].specifier, Some(Specifier::Plt));
+    }
+
+    #[test]
+    fn test_insn() {
+        let encode = |line: &str| parse_insn(&tokenize(line)).unwrap().encode().unwrap().get_bytes().clone();
+
+        assert_eq!(encode(".insn 0x0f 0xc7 /7, eax"), vec![0x0F, 0xC7, 0xF8]);
+        assert_eq!(encode(".insn 0x0fc7/7, r9"), vec![0x49, 0x0F, 0xC7, 0xF9]);
+        assert_eq!(encode(".insn 0x0f 0xaf, rax, qword ptr [rbx + 8]"), vec![0x48, 0x0F, 0xAF, 0x43, 0x08]);
+        assert_eq!(encode(".insn 0x89, qword ptr [rsp + 16], r12"), vec![0x4C, 0x89, 0x64, 0x24, 0x10]);
+        assert_eq!(encode(".insn 0xf3 0x0f 0xb8, r10, qword ptr [r13]"), vec![0xF3, 0x4D, 0x0F, 0xB8, 0x55, 0x00]);
+        assert_eq!(encode(".insn 0x81 /0, word ptr [rax], 300"), vec![0x66, 0x81, 0x00, 0x2C, 0x01]);
+
+        let lea = parse_insn(&tokenize(".insn 0x8d, rdi, [rip + msg]")).unwrap().encode().unwrap();
+        assert_eq!(lea.get_refs().len(), 1);
+        assert_eq!(lea.get_refs()[0].at, 3);
+
+        assert!(parse_insn(&tokenize(".insn 0x89, rax")).is_err());
+        assert!(parse_insn(&tokenize(".insn 0x0f /7 /3, eax")).is_err());
+    }
+
+    #[test]
+    fn test_features() {
+        let v2 = FeatureSet::level("x86-64-v2").unwrap();
+        assert!(v2.contains("POPCNT"));
+        assert!(v2.contains("SSE4_2"));
+        assert!(!v2.contains("LZCNT"));
+        assert!(FeatureSet::level("x86-64-v3").unwrap().contains("LZCNT"));
+        assert!(FeatureSet::level("x86-64-v5").is_none());
+
+        let mut features = v2.clone();
+        features.apply(".sse4.2").unwrap();
+        features.apply(".rdseed").unwrap();
+        features.apply(".nopopcnt").unwrap();
+        assert!(features.contains("RDSEED"));
+        assert!(!features.contains("POPCNT"));
+
+        let rdseed = matches(&tokenize("rdseed eax"), &v2);
+        assert!(matches!(rdseed, Err((1, ParseError::DisabledFeature(_)))));
+        let rdseed = matches(&tokenize("rdseed eax"), &features).unwrap();
+        assert_eq!(rdseed.encode().unwrap().get_bytes(), &vec![0x0F, 0xC7, 0xF8]);
+        assert_eq!(Encodable::feature(&rdseed), Some("RDSEED"));
+
+        let push = matches(&tokenize("push rbp"), &v2).unwrap();
+        assert_eq!(Encodable::feature(&push), None);
+    }
+}
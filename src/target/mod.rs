@@ -0,0 +1,133 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+mod riscv64;
+mod tests;
+mod x86_64;
+
+use crate::assembler::ObjectFileType;
+use crate::instructions::{Encodable, Reference};
+use crate::parse::lexer::Token;
+use crate::parse::{ParseError, Syntax};
+use object::{Architecture, RelocationEncoding, RelocationKind};
+
+pub use riscv64::RiscV64;
+pub use x86_64::X86_64;
+
+/// How a [`Reference`] is written into an object file
+pub struct RelocationType {
+    pub kind: RelocationKind,
+    pub encoding: RelocationEncoding,
+    pub size: u8,
+    pub addend: i64,
+    /// For differences, the relocation of the subtracted symbol, which Mach-O writes right before the other one.
+    /// Without it, differences are pc-relative
+    pub subtractor: Option<RelocationKind>,
+}
+
+/// Everything the assembler needs to know about an instruction set. The lexer, directives, sections, symbols and
+/// the object writer are shared, only registers, instructions, their encoding and relocations differ between targets.
+pub trait Target {
+    /// The name used to select this target on the command line
+    fn name(&self) -> &'static str;
+
+    fn architecture(&self) -> Architecture;
+
+    fn is_register(&self, name: &str) -> bool;
+
+    /// Matches a line of tokens against the targets instruction set
+    fn parse_instruction(&self, tokens: &[Token]) -> Result<Box<dyn Encodable>, (usize, ParseError)>;
+
+    /// Parses a `.insn` directive, that spells out an instructions encoding
+    fn parse_raw_instruction(&self, tokens: &[Token]) -> Result<Box<dyn Encodable>, (usize, ParseError)> {
+        Err((tokens.len() - 1, ParseError::InvalidDirective))
+    }
+
+    /// Restricts instructions to a CPU level or extension, as selected by `--march` or `.arch`
+    fn set_arch(&mut self, arch: &str) -> Result<(), String> {
+        Err(format!("Unknown architecture '{}' for {}", arch, self.name()))
+    }
+
+    /// Switches the syntax instructions are parsed in, as selected by `--syntax` or `.att_syntax`/`.intel_syntax`
+    fn set_syntax(&mut self, syntax: Syntax) -> Result<(), String> {
+        match syntax {
+            Syntax::Intel => Ok(()),
+            _ => Err(format!("{} doesn't support {:?} syntax", self.name(), syntax)),
+        }
+    }
+
+    /// Sets whether registers are written with a `%` in front, as after `.intel_syntax prefix`
+    fn set_register_prefix(&mut self, prefix: bool) -> Result<(), String> {
+        if prefix {
+            Err(format!("{} doesn't support register prefixes", self.name()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Makes memory operands that are only a symbol relative to the instruction pointer, as selected by
+    /// `--default-rel` or NASM's `default rel`
+    fn set_default_rel(&mut self, default_rel: bool) -> Result<(), String> {
+        if default_rel {
+            Err(format!("{} doesn't support rip-relative addressing", self.name()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Sets whether relocations the linker may rewrite instructions for are used, as selected by
+    /// `-mrelax-relocations`. Targets without them have nothing to turn off
+    fn set_relax_relocations(&mut self, relax: bool) -> Result<(), String> {
+        let _ = relax;
+        Ok(())
+    }
+
+    /// Adds instructions from a table in the x86-csv format, returning how many were added
+    fn load_instruction_table(&mut self, csv: &str) -> Result<usize, String> {
+        let _ = csv;
+        Err(format!("{} doesn't support loading instruction tables", self.name()))
+    }
+
+    /// Lists the forms of an instruction, or of every instruction, as pairs of their syntax and a description
+    fn instruction_forms(&self, _mnemonic: Option<&str>) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// Fills `count` bytes with instructions that do nothing, to pad code. `limit` caps the size of each one, like the
+    /// second argument of `.nops`, and is `None` if the target doesn't have NOPs that small
+    fn nops(&self, count: usize, limit: Option<usize>) -> Option<Vec<u8>>;
+
+    /// Whether `.align` is in bytes rather than a power of two, which depends on the object file format on some targets
+    fn align_in_bytes(&self, format: ObjectFileType) -> bool {
+        let _ = format;
+        false
+    }
+
+    /// Maps a reference made by an instruction to the relocation used for the given object file format. Preemptible
+    /// symbols are undefined or global ones, which may end up in another module when linking
+    fn relocation(
+        &self,
+        reference: &Reference,
+        preemptible: bool,
+        format: ObjectFileType,
+    ) -> Result<RelocationType, String>;
+}
+
+pub const TARGETS: &[&str] = &["x86_64", "riscv64"];
+
+pub fn target_from_name(name: &str) -> Option<Box<dyn Target>> {
+    match name {
+        "x86_64" | "x86-64" | "amd64" => Some(Box::new(X86_64::default())),
+        "riscv64" | "rv64i" => Some(Box::new(RiscV64)),
+        _ => None,
+    }
+}
+
+impl Default for Box<dyn Target> {
+    fn default() -> Self {
+        Box::new(X86_64::default())
+    }
+}
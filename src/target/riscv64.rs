@@ -0,0 +1,558 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use crate::assembler::ObjectFileType;
+use crate::instructions::{Encodable, EncodedInstruction, Reference};
+use crate::parse::expression::evaluate;
+use crate::parse::helpers::{get_next, Relativity};
+use crate::parse::lexer::Token;
+use crate::parse::ParseError;
+use crate::target::{RelocationType, Target};
+use object::{elf, Architecture, RelocationEncoding, RelocationKind};
+use std::slice::Iter;
+
+/// The RV64I base integer instruction set
+pub struct RiscV64;
+
+const ABI_NAMES: [&str; 32] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4", "a5", "a6", "a7", "s2",
+    "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4", "t5", "t6",
+];
+
+const ZERO: u32 = 0;
+const RA: u32 = 1;
+const T1: u32 = 6;
+
+fn register(name: &str) -> Option<u32> {
+    if name == "fp" {
+        return Some(8);
+    }
+    if let Some(number) = name.strip_prefix('x') {
+        return number.parse::<u32>().ok().filter(|n| *n < 32);
+    }
+    ABI_NAMES.iter().position(|r| *r == name).map(|r| r as u32)
+}
+
+#[derive(Copy, Clone)]
+enum Format {
+    /// funct7, funct3, opcode
+    R(u32, u32, u32),
+    /// funct3, opcode
+    I(u32, u32),
+    /// funct6, funct3, opcode, shift amount bits
+    Shift(u32, u32, u32, u32),
+    /// funct3
+    Load(u32),
+    /// funct3
+    Store(u32),
+    /// funct3
+    Branch(u32),
+    /// opcode
+    U(u32),
+    Jal,
+    Jalr,
+    /// A complete instruction without operands
+    Fixed(u32),
+}
+
+use Format::*;
+
+const OP: u32 = 0b0110011;
+const OP_32: u32 = 0b0111011;
+const OP_IMM: u32 = 0b0010011;
+const OP_IMM_32: u32 = 0b0011011;
+const LOAD: u32 = 0b0000011;
+const STORE: u32 = 0b0100011;
+const BRANCH: u32 = 0b1100011;
+const JALR: u32 = 0b1100111;
+const JAL: u32 = 0b1101111;
+const LUI: u32 = 0b0110111;
+const AUIPC: u32 = 0b0010111;
+
+const INSTRUCTIONS: &[(&str, Format)] = &[
+    ("lui", U(LUI)),
+    ("auipc", U(AUIPC)),
+    ("jal", Jal),
+    ("jalr", Jalr),
+    ("beq", Branch(0b000)),
+    ("bne", Branch(0b001)),
+    ("blt", Branch(0b100)),
+    ("bge", Branch(0b101)),
+    ("bltu", Branch(0b110)),
+    ("bgeu", Branch(0b111)),
+    ("lb", Load(0b000)),
+    ("lh", Load(0b001)),
+    ("lw", Load(0b010)),
+    ("ld", Load(0b011)),
+    ("lbu", Load(0b100)),
+    ("lhu", Load(0b101)),
+    ("lwu", Load(0b110)),
+    ("sb", Store(0b000)),
+    ("sh", Store(0b001)),
+    ("sw", Store(0b010)),
+    ("sd", Store(0b011)),
+    ("addi", I(0b000, OP_IMM)),
+    ("slti", I(0b010, OP_IMM)),
+    ("sltiu", I(0b011, OP_IMM)),
+    ("xori", I(0b100, OP_IMM)),
+    ("ori", I(0b110, OP_IMM)),
+    ("andi", I(0b111, OP_IMM)),
+    ("slli", Shift(0b000000, 0b001, OP_IMM, 6)),
+    ("srli", Shift(0b000000, 0b101, OP_IMM, 6)),
+    ("srai", Shift(0b010000, 0b101, OP_IMM, 6)),
+    ("add", R(0b0000000, 0b000, OP)),
+    ("sub", R(0b0100000, 0b000, OP)),
+    ("sll", R(0b0000000, 0b001, OP)),
+    ("slt", R(0b0000000, 0b010, OP)),
+    ("sltu", R(0b0000000, 0b011, OP)),
+    ("xor", R(0b0000000, 0b100, OP)),
+    ("srl", R(0b0000000, 0b101, OP)),
+    ("sra", R(0b0100000, 0b101, OP)),
+    ("or", R(0b0000000, 0b110, OP)),
+    ("and", R(0b0000000, 0b111, OP)),
+    ("addiw", I(0b000, OP_IMM_32)),
+    ("slliw", Shift(0b000000, 0b001, OP_IMM_32, 5)),
+    ("srliw", Shift(0b000000, 0b101, OP_IMM_32, 5)),
+    ("sraiw", Shift(0b010000, 0b101, OP_IMM_32, 5)),
+    ("addw", R(0b0000000, 0b000, OP_32)),
+    ("subw", R(0b0100000, 0b000, OP_32)),
+    ("sllw", R(0b0000000, 0b001, OP_32)),
+    ("srlw", R(0b0000000, 0b101, OP_32)),
+    ("sraw", R(0b0100000, 0b101, OP_32)),
+    ("fence", Fixed(0x0FF0000F)),
+    ("ecall", Fixed(0x00000073)),
+    ("ebreak", Fixed(0x00100073)),
+];
+
+fn r_type(funct7: u32, rs2: u32, rs1: u32, funct3: u32, rd: u32, opcode: u32) -> u32 {
+    funct7 << 25 | rs2 << 20 | rs1 << 15 | funct3 << 12 | rd << 7 | opcode
+}
+
+fn i_type(imm: i64, rs1: u32, funct3: u32, rd: u32, opcode: u32) -> u32 {
+    (imm as u32 & 0xFFF) << 20 | rs1 << 15 | funct3 << 12 | rd << 7 | opcode
+}
+
+fn s_type(imm: i64, rs2: u32, rs1: u32, funct3: u32) -> u32 {
+    let imm = imm as u32;
+    (imm >> 5 & 0x7F) << 25 | rs2 << 20 | rs1 << 15 | funct3 << 12 | (imm & 0x1F) << 7 | STORE
+}
+
+fn b_type(rs2: u32, rs1: u32, funct3: u32) -> u32 {
+    // Branch targets are always symbols, so the immediate is filled in by the linker
+    rs2 << 20 | rs1 << 15 | funct3 << 12 | BRANCH
+}
+
+fn u_type(imm: i64, rd: u32, opcode: u32) -> u32 {
+    (imm as u32 & 0xFFFFF) << 12 | rd << 7 | opcode
+}
+
+/// An immediate that is either known, or filled in by the linker
+enum Value {
+    Integer(i64),
+    Symbol(String),
+}
+
+impl Value {
+    fn integer(&self) -> i64 {
+        match self {
+            Value::Integer(i) => *i,
+            Value::Symbol(_) => 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RiscVInstruction {
+    name: String,
+    words: Vec<u32>,
+    refs: Vec<(usize, String, Relativity)>,
+}
+
+impl RiscVInstruction {
+    fn new(name: &str) -> Self {
+        RiscVInstruction { name: name.to_string(), words: Vec::new(), refs: Vec::new() }
+    }
+
+    fn word(mut self, word: u32) -> Self {
+        self.words.push(word);
+        self
+    }
+
+    fn word_with(mut self, word: u32, value: Value, rel: Relativity) -> Self {
+        if let Value::Symbol(to) = value {
+            self.refs.push((self.words.len() * 4, to, rel));
+        }
+        self.words.push(word);
+        self
+    }
+}
+
+impl Encodable for RiscVInstruction {
+    fn encode(&self) -> Result<EncodedInstruction, String> {
+        let mut encoded = EncodedInstruction::new(self.name.clone());
+
+        for (at, to, rel) in &self.refs {
+            let to_end = self.words.len() * 4 - at;
+            encoded.add_reference(Reference {
+                to: to.clone(),
+                minus: None,
+                addend: 0,
+                at: *at,
+                rel: *rel,
+                specifier: None,
+                size: 32,
+                signed: true,
+                to_end,
+                relax: None,
+            });
+        }
+        for word in &self.words {
+            encoded.write_num(*word as i32);
+        }
+
+        Ok(encoded)
+    }
+}
+
+fn expect(iter: &mut Iter<Token>, s: &str) -> Result<(), (usize, ParseError)> {
+    if get_next(iter)? != s {
+        return Err((iter.count(), ParseError::InvalidOperand));
+    }
+    Ok(())
+}
+
+fn is_reg(iter: &mut Iter<Token>) -> Result<u32, (usize, ParseError)> {
+    let next = get_next(iter)?;
+    register(next.as_str()).ok_or_else(|| (iter.count(), ParseError::InvalidOperand))
+}
+
+fn is_symbol(iter: &mut Iter<Token>) -> Result<String, (usize, ParseError)> {
+    let next = get_next(iter)?;
+    if register(next.as_str()).is_some() || next.as_str().starts_with(|c: char| c.is_ascii_digit()) {
+        return Err((iter.count(), ParseError::InvalidOperand));
+    }
+    Ok(next.clone_string())
+}
+
+/// Parses a constant expression up to the end of the operand, which is before the register of `offset(register)`
+fn is_integer(iter: &mut Iter<Token>, min: i64, max: i64) -> Result<i64, (usize, ParseError)> {
+    let rest = iter.as_slice();
+    let mut len = rest.iter().position(|t| t == ",").unwrap_or(rest.len());
+    if let [.., open, reg, close] = &rest[..len] {
+        if *open == "(" && register(reg.as_str()).is_some() && *close == ")" {
+            len -= 3;
+        }
+    }
+    if len == 0 {
+        return Err((rest.len(), ParseError::InvalidOperand));
+    }
+
+    let num = evaluate(&rest[..len], rest.len() - len)?;
+    iter.nth(len - 1);
+    if num >= min && num <= max {
+        Ok(num)
+    } else {
+        Err((iter.count(), ParseError::InvalidOperand))
+    }
+}
+
+/// Parses either a 12-bit signed integer or `%lo(symbol)`
+fn is_imm12(iter: &mut Iter<Token>) -> Result<Value, (usize, ParseError)> {
+    if iter.clone().next().map(|t| t == "%lo").unwrap_or(false) {
+        iter.next();
+        expect(iter, "(")?;
+        let symbol = is_symbol(iter)?;
+        expect(iter, ")")?;
+        Ok(Value::Symbol(symbol))
+    } else {
+        Ok(Value::Integer(is_integer(iter, -2048, 2047)?))
+    }
+}
+
+/// Parses either a 20-bit integer or `%hi(symbol)`
+fn is_imm20(iter: &mut Iter<Token>) -> Result<Value, (usize, ParseError)> {
+    if iter.clone().next().map(|t| t == "%hi").unwrap_or(false) {
+        iter.next();
+        expect(iter, "(")?;
+        let symbol = is_symbol(iter)?;
+        expect(iter, ")")?;
+        Ok(Value::Symbol(symbol))
+    } else {
+        Ok(Value::Integer(is_integer(iter, -0x80000, 0xFFFFF)?))
+    }
+}
+
+/// Parses `offset(register)`, where the offset is optional
+fn is_memory(iter: &mut Iter<Token>) -> Result<(Value, u32), (usize, ParseError)> {
+    let offset = if iter.clone().next().map(|t| t == "(").unwrap_or(false) {
+        Value::Integer(0)
+    } else {
+        is_imm12(iter)?
+    };
+    expect(iter, "(")?;
+    let reg = is_reg(iter)?;
+    expect(iter, ")")?;
+    Ok((offset, reg))
+}
+
+fn end(iter: &mut Iter<Token>) -> Result<(), (usize, ParseError)> {
+    if iter.next().is_some() {
+        return Err((iter.count(), ParseError::ExtraneousTokenAfterInstruction));
+    }
+    Ok(())
+}
+
+fn matches_format(name: &str, format: Format, iter: &mut Iter<Token>) -> Result<RiscVInstruction, (usize, ParseError)> {
+    let instr = RiscVInstruction::new(name);
+
+    let instr = match format {
+        R(funct7, funct3, opcode) => {
+            let rd = is_reg(iter)?;
+            expect(iter, ",")?;
+            let rs1 = is_reg(iter)?;
+            expect(iter, ",")?;
+            let rs2 = is_reg(iter)?;
+            instr.word(r_type(funct7, rs2, rs1, funct3, rd, opcode))
+        }
+        I(funct3, opcode) => {
+            let rd = is_reg(iter)?;
+            expect(iter, ",")?;
+            let rs1 = is_reg(iter)?;
+            expect(iter, ",")?;
+            let imm = is_imm12(iter)?;
+            instr.word_with(i_type(imm.integer(), rs1, funct3, rd, opcode), imm, Relativity::Low)
+        }
+        Shift(funct6, funct3, opcode, bits) => {
+            let rd = is_reg(iter)?;
+            expect(iter, ",")?;
+            let rs1 = is_reg(iter)?;
+            expect(iter, ",")?;
+            let shamt = is_integer(iter, 0, (1 << bits) - 1)?;
+            instr.word(i_type((funct6 << 6) as i64 | shamt, rs1, funct3, rd, opcode))
+        }
+        Load(funct3) => {
+            let rd = is_reg(iter)?;
+            expect(iter, ",")?;
+            let (offset, rs1) = is_memory(iter)?;
+            instr.word_with(i_type(offset.integer(), rs1, funct3, rd, LOAD), offset, Relativity::Low)
+        }
+        Store(funct3) => {
+            let rs2 = is_reg(iter)?;
+            expect(iter, ",")?;
+            let (offset, rs1) = is_memory(iter)?;
+            instr.word_with(s_type(offset.integer(), rs2, rs1, funct3), offset, Relativity::LowStore)
+        }
+        Branch(funct3) => {
+            let rs1 = is_reg(iter)?;
+            expect(iter, ",")?;
+            let rs2 = is_reg(iter)?;
+            expect(iter, ",")?;
+            let to = is_symbol(iter)?;
+            instr.word_with(b_type(rs2, rs1, funct3), Value::Symbol(to), Relativity::Branch)
+        }
+        U(opcode) => {
+            let rd = is_reg(iter)?;
+            expect(iter, ",")?;
+            let imm = is_imm20(iter)?;
+            instr.word_with(u_type(imm.integer(), rd, opcode), imm, Relativity::High)
+        }
+        Jal => {
+            // `jal label` links to ra
+            let rd = if iter.len() > 1 {
+                let rd = is_reg(iter)?;
+                expect(iter, ",")?;
+                rd
+            } else {
+                RA
+            };
+            let to = is_symbol(iter)?;
+            instr.word_with(rd << 7 | JAL, Value::Symbol(to), Relativity::Jump)
+        }
+        Jalr => {
+            // `jalr rs1` links to ra
+            if iter.len() == 1 {
+                let rs1 = is_reg(iter)?;
+                instr.word(i_type(0, rs1, 0, RA, JALR))
+            } else {
+                let rd = is_reg(iter)?;
+                expect(iter, ",")?;
+                let (offset, rs1) = is_memory(iter)?;
+                instr.word_with(i_type(offset.integer(), rs1, 0, rd, JALR), offset, Relativity::Low)
+            }
+        }
+        Fixed(word) => instr.word(word),
+    };
+
+    end(iter)?;
+    Ok(instr)
+}
+
+/// Expands pseudo instructions into the base instructions they stand for
+fn matches_pseudo(name: &str, iter: &mut Iter<Token>) -> Option<Result<RiscVInstruction, (usize, ParseError)>> {
+    let mut pseudo = || -> Result<RiscVInstruction, (usize, ParseError)> {
+        let instr = RiscVInstruction::new(name);
+
+        let instr = match name {
+            "nop" => instr.word(i_type(0, ZERO, 0, ZERO, OP_IMM)),
+            "ret" => instr.word(i_type(0, RA, 0, ZERO, JALR)),
+            "mv" | "not" | "neg" | "negw" | "sext.w" | "seqz" | "snez" => {
+                let rd = is_reg(iter)?;
+                expect(iter, ",")?;
+                let rs = is_reg(iter)?;
+                instr.word(match name {
+                    "mv" => i_type(0, rs, 0b000, rd, OP_IMM),
+                    "not" => i_type(-1, rs, 0b100, rd, OP_IMM),
+                    "neg" => r_type(0b0100000, rs, ZERO, 0b000, rd, OP),
+                    "negw" => r_type(0b0100000, rs, ZERO, 0b000, rd, OP_32),
+                    "sext.w" => i_type(0, rs, 0b000, rd, OP_IMM_32),
+                    "seqz" => i_type(1, rs, 0b011, rd, OP_IMM),
+                    _ => r_type(0, rs, ZERO, 0b011, rd, OP),
+                })
+            }
+            "li" => {
+                let rd = is_reg(iter)?;
+                expect(iter, ",")?;
+                let imm = is_integer(iter, i32::MIN as i64, i32::MAX as i64)?;
+                if (-2048..2048).contains(&imm) {
+                    instr.word(i_type(imm, ZERO, 0b000, rd, OP_IMM))
+                } else {
+                    let hi = (imm + 0x800) >> 12;
+                    let lo = imm - (hi << 12);
+                    instr.word(u_type(hi, rd, LUI)).word(i_type(lo, rd, 0b000, rd, OP_IMM_32))
+                }
+            }
+            "beqz" | "bnez" | "bltz" | "bgez" | "blez" | "bgtz" => {
+                let rs = is_reg(iter)?;
+                expect(iter, ",")?;
+                let to = Value::Symbol(is_symbol(iter)?);
+                instr.word_with(
+                    match name {
+                        "beqz" => b_type(ZERO, rs, 0b000),
+                        "bnez" => b_type(ZERO, rs, 0b001),
+                        "bltz" => b_type(ZERO, rs, 0b100),
+                        "bgez" => b_type(ZERO, rs, 0b101),
+                        "blez" => b_type(rs, ZERO, 0b101),
+                        _ => b_type(rs, ZERO, 0b100),
+                    },
+                    to,
+                    Relativity::Branch,
+                )
+            }
+            "bgt" | "ble" | "bgtu" | "bleu" => {
+                // These are the base branches with their operands swapped
+                let rs1 = is_reg(iter)?;
+                expect(iter, ",")?;
+                let rs2 = is_reg(iter)?;
+                expect(iter, ",")?;
+                let to = Value::Symbol(is_symbol(iter)?);
+                let funct3 = match name {
+                    "bgt" => 0b100,
+                    "ble" => 0b101,
+                    "bgtu" => 0b110,
+                    _ => 0b111,
+                };
+                instr.word_with(b_type(rs1, rs2, funct3), to, Relativity::Branch)
+            }
+            "j" => {
+                let to = Value::Symbol(is_symbol(iter)?);
+                instr.word_with(JAL, to, Relativity::Jump)
+            }
+            "jr" => {
+                let rs = is_reg(iter)?;
+                instr.word(i_type(0, rs, 0, ZERO, JALR))
+            }
+            "call" | "tail" => {
+                let (link, scratch) = if name == "call" { (RA, RA) } else { (ZERO, T1) };
+                let to = Value::Symbol(is_symbol(iter)?);
+                // The relocation covers both the auipc and the jalr
+                instr.word_with(u_type(0, scratch, AUIPC), to, Relativity::Call).word(i_type(0, scratch, 0, link, JALR))
+            }
+            _ => unreachable!(),
+        };
+
+        end(iter)?;
+        Ok(instr)
+    };
+
+    const PSEUDOS: &[&str] = &[
+        "nop", "ret", "mv", "not", "neg", "negw", "sext.w", "seqz", "snez", "li", "beqz", "bnez", "bltz", "bgez", "blez",
+        "bgtz", "bgt", "ble", "bgtu", "bleu", "j", "jr", "call", "tail",
+    ];
+
+    if PSEUDOS.contains(&name) {
+        Some(pseudo())
+    } else {
+        None
+    }
+}
+
+impl Target for RiscV64 {
+    fn name(&self) -> &'static str {
+        "riscv64"
+    }
+
+    fn architecture(&self) -> Architecture {
+        Architecture::Riscv64
+    }
+
+    fn is_register(&self, name: &str) -> bool {
+        register(name).is_some()
+    }
+
+    fn parse_instruction(&self, tokens: &[Token]) -> Result<Box<dyn Encodable>, (usize, ParseError)> {
+        let mut iter = tokens.iter();
+        let name = get_next(&mut iter)?.clone_string();
+        let name = name.as_str();
+
+        if let Some(pseudo) = matches_pseudo(name, &mut iter) {
+            return Ok(Box::new(pseudo?));
+        }
+
+        let format = INSTRUCTIONS.iter().find(|(n, _)| *n == name);
+        if let Some((_, format)) = format {
+            Ok(Box::new(matches_format(name, *format, &mut iter)?))
+        } else {
+            Err((iter.count(), ParseError::InvalidInstruction))
+        }
+    }
+
+    fn nops(&self, count: usize, limit: Option<usize>) -> Option<Vec<u8>> {
+        if limit.is_some_and(|limit| limit < 4) {
+            return None;
+        }
+
+        // Bytes before the first instruction boundary can't be executed anyway
+        let mut bytes = vec![0; count % 4];
+        for _ in 0..count / 4 {
+            bytes.extend_from_slice(&i_type(0, ZERO, 0, ZERO, OP_IMM).to_le_bytes());
+        }
+        Some(bytes)
+    }
+
+    fn relocation(&self, reference: &Reference, _: bool, format: ObjectFileType) -> Result<RelocationType, String> {
+        if format != ObjectFileType::Elf {
+            return Err("RISC-V objects can only be written as ELF".to_string());
+        }
+
+        let r_type = match reference.rel {
+            Relativity::Branch => elf::R_RISCV_BRANCH,
+            Relativity::Jump => elf::R_RISCV_JAL,
+            Relativity::Call => elf::R_RISCV_CALL_PLT,
+            Relativity::High => elf::R_RISCV_HI20,
+            Relativity::Low => elf::R_RISCV_LO12_I,
+            Relativity::LowStore => elf::R_RISCV_LO12_S,
+            _ => return Err(format!("Relocation {:?} is not supported on riscv64", reference.rel)),
+        };
+
+        Ok(RelocationType {
+            kind: RelocationKind::Elf(r_type),
+            encoding: RelocationEncoding::Generic,
+            size: 32,
+            addend: 0,
+            subtractor: None,
+        })
+    }
+}
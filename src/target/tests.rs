@@ -0,0 +1,413 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+#[cfg(test)]
+mod tests {
+    use crate::assembler::ObjectFileType;
+    use crate::parse::helpers::{Relativity, Specifier};
+    use crate::parse::lexer::{Lexer, Token};
+    use crate::parse::{ParseError, Syntax};
+    use crate::target::{RiscV64, Target, X86_64};
+    use crate::instructions::{Reference, Subtrahend};
+    use object::{elf, macho, RelocationEncoding, RelocationKind};
+
+    fn tokenize(line: &str) -> Vec<Token> {
+        let mut lexer = Lexer::new(line.to_string());
+        let mut tokens = Vec::new();
+        while let Ok(token) = lexer.read() {
+            tokens.push(token);
+        }
+        tokens
+    }
+
+    fn encode_words(target: &dyn Target, line: &str) -> Vec<u32> {
+        let instr = target.parse_instruction(&tokenize(line)).unwrap();
+        instr.encode().unwrap().get_bytes().chunks(4).map(|w| u32::from_le_bytes(w.try_into().unwrap())).collect()
+    }
+
+    #[test]
+    fn test_riscv64() {
+        assert_eq!(encode_words(&RiscV64, "addi a0, a0, 1"), vec![0x00150513]);
+        assert_eq!(encode_words(&RiscV64, "sub a0, a1, a2"), vec![0x40C58533]);
+        assert_eq!(encode_words(&RiscV64, "ld ra, 8(sp)"), vec![0x00813083]);
+        assert_eq!(encode_words(&RiscV64, "sd ra, 8(sp)"), vec![0x00113423]);
+        assert_eq!(encode_words(&RiscV64, "addi sp, sp, -16"), vec![0xFF010113]);
+        assert_eq!(encode_words(&RiscV64, "ret"), vec![0x00008067]);
+        assert_eq!(encode_words(&RiscV64, "li a0, 0x12345"), vec![0x00012537, 0x3455051B]);
+        assert_eq!(encode_words(&RiscV64, "beq a0, a1, loop"), vec![0x00B50063]);
+
+        assert_eq!(encode_words(&RiscV64, "addi sp, sp, -(8 * 2)"), vec![0xFF010113]);
+        assert_eq!(encode_words(&RiscV64, "ld ra, 4 + 4(sp)"), vec![0x00813083]);
+
+        assert!(RiscV64.parse_instruction(&tokenize("addi a0, a0, 4096")).is_err());
+        assert!(RiscV64.parse_instruction(&tokenize("addi a0, a0, 2048 * 2 - 1")).is_err());
+        assert!(RiscV64.parse_instruction(&tokenize("push rbp")).is_err());
+    }
+
+    #[test]
+    fn test_riscv64_references() {
+        let call = RiscV64.parse_instruction(&tokenize("call puts")).unwrap().encode().unwrap();
+        assert_eq!(call.get_bytes(), &vec![0x97, 0x00, 0x00, 0x00, 0xE7, 0x80, 0x00, 0x00]);
+        assert_eq!(call.get_refs().len(), 1);
+        assert_eq!(call.get_refs()[0].to, "puts");
+        assert_eq!(call.get_refs()[0].rel, Relativity::Call);
+
+        let lo = RiscV64.parse_instruction(&tokenize("ld a0, %lo(msg)(a0)")).unwrap().encode().unwrap();
+        assert_eq!(lo.get_refs()[0].rel, Relativity::Low);
+    }
+
+    #[test]
+    fn test_x86_64() {
+        let push = X86_64::default().parse_instruction(&tokenize("push rbp")).unwrap();
+        assert_eq!(push.encode().unwrap().get_bytes(), &vec![0x55u8]);
+        // Immediates the CPU doesn't sign extend can be written unsigned, the others take a wider form
+        let encode = |code: &str| {
+            let instruction = X86_64::default().parse_instruction(&tokenize(code)).ok()?;
+            Some(instruction.encode().ok()?.get_bytes().clone())
+        };
+        assert_eq!(encode("add al, 200"), Some(vec![0x04, 0xC8]));
+        assert_eq!(encode("mov byte ptr [rax], 200"), Some(vec![0xC6, 0x00, 0xC8]));
+        assert_eq!(encode("mov eax, 4294967295"), Some(vec![0xB8, 0xFF, 0xFF, 0xFF, 0xFF]));
+        assert_eq!(encode("add eax, 200"), Some(vec![0x05, 0xC8, 0x00, 0x00, 0x00]));
+        assert_eq!(encode("push 200"), Some(vec![0x68, 0xC8, 0x00, 0x00, 0x00]));
+        assert!(encode("add rax, 4294967295").is_none());
+        assert!(encode("add al, 256").is_none());
+
+        // 40+rd and 48+rd are REX prefixes in 64-bit mode
+        let inc = X86_64::default().parse_instruction(&tokenize("inc eax")).unwrap();
+        assert_eq!(inc.encode().unwrap().get_bytes(), &vec![0xFFu8, 0xC0]);
+        let dec = X86_64::default().parse_instruction(&tokenize("dec eax")).unwrap();
+        assert_eq!(dec.encode().unwrap().get_bytes(), &vec![0xFFu8, 0xC8]);
+        assert!(X86_64::default().parse_instruction(&tokenize("push eax")).is_err());
+        let nop = X86_64::default().parse_instruction(&tokenize("nop")).unwrap();
+        assert_eq!(nop.encode().unwrap().get_bytes(), &vec![0x90u8]);
+        let nop = X86_64::default().parse_instruction(&tokenize("nop dword ptr [rax]")).unwrap();
+        assert_eq!(nop.encode().unwrap().get_bytes(), &vec![0x0Fu8, 0x1F, 0x00]);
+
+        assert!(X86_64::default().is_register("rax"));
+        assert!(!X86_64::default().is_register("a0"));
+        assert!(RiscV64.is_register("a0"));
+    }
+
+    #[test]
+    fn test_x86_64_att() {
+        let mut att = X86_64::default();
+        att.set_syntax(Syntax::Att).unwrap();
+        let intel = X86_64::default();
+
+        for (att_line, intel_line) in [
+            ("movq %rsp, %rbp", "mov rbp, rsp"),
+            ("leaq 8(%rax,%rbx,4), %rdi", "lea rdi, [rax + rbx*4 + 8]"),
+            ("movl $5, -4(%rbp)", "mov dword ptr [rbp - 4], 5"),
+            ("addq $0x10, %rsp", "add rsp, 16"),
+            ("movzbl (%rax), %ecx", "movzx ecx, byte ptr [rax]"),
+            ("movslq %esi, %rsi", "movsxd rsi, esi"),
+            ("movq %fs:0x28, %rax", "mov rax, qword ptr fs:[0x28]"),
+            ("leaq (,%rcx,8), %rdx", "lea rdx, [rcx*8]"),
+            ("call *%rax", "call rax"),
+            ("jmpq *8(%rax)", "jmp qword ptr [rax + 8]"),
+            ("sarl (%rbx)", "sar dword ptr [rbx], 1"),
+            ("cltq", "cdqe"),
+            ("movabsq $0x123456789, %rax", "mov rax, 0x123456789"),
+            ("enter $16, $1", "enter 16, 1"),
+            ("retq", "ret"),
+        ] {
+            let expected = intel.parse_instruction(&tokenize(intel_line)).unwrap().encode().unwrap();
+            let actual = att.parse_instruction(&tokenize(att_line)).unwrap().encode().unwrap();
+            assert_eq!(actual.get_bytes(), expected.get_bytes(), "{}", att_line);
+        }
+
+        let lea = att.parse_instruction(&tokenize("leaq msg(%rip), %rdi")).unwrap().encode().unwrap();
+        assert_eq!(lea.get_refs()[0].to, "msg");
+        assert_eq!(lea.get_refs()[0].rel, Relativity::RipRelative);
+        let call = att.parse_instruction(&tokenize("call puts")).unwrap().encode().unwrap();
+        assert_eq!(call.get_refs()[0].to, "puts");
+
+        // Errors point at the AT&T tokens
+        let err = att.parse_instruction(&tokenize("movq %rax, %ecx"));
+        assert!(matches!(err, Err((2, ParseError::InvalidOperand))));
+        let err = att.parse_instruction(&tokenize("movq (%rax,%rbx,3), %rax"));
+        assert!(matches!(err, Err((3, ParseError::InvalidOperand))));
+        let err = att.parse_instruction(&tokenize("movq rax, %rbx"));
+        assert!(matches!(err, Err((2, ParseError::InvalidOperand))));
+    }
+
+    #[test]
+    fn test_x86_64_default_rel() {
+        let mut x86 = X86_64::default();
+        x86.set_default_rel(true).unwrap();
+
+        let encode = |line: &str| x86.parse_instruction(&tokenize(line)).unwrap().encode().unwrap();
+        assert_eq!(encode("mov eax, [msg]").get_bytes(), &vec![0x8B, 0x05, 0, 0, 0, 0]);
+        assert_eq!(encode("mov eax, [msg]").get_refs()[0].rel, Relativity::RipRelative);
+        assert_eq!(encode("lea rdi, [\"rax\"]").get_bytes(), &vec![0x48, 0x8D, 0x3D, 0, 0, 0, 0]);
+        assert_eq!(encode("mov eax, [abs msg]").get_bytes(), &vec![0x8B, 0x04, 0x25, 0, 0, 0, 0]);
+        assert_eq!(encode("mov eax, fs:[msg]").get_bytes(), &vec![0x64, 0x8B, 0x04, 0x25, 0, 0, 0, 0]);
+        assert_eq!(encode("mov eax, [rbx]").get_bytes(), &vec![0x8B, 0x03]);
+        assert_eq!(encode("mov eax, [0x10]").get_bytes(), &vec![0x8B, 0x04, 0x25, 0x10, 0, 0, 0]);
+
+        // Without default rel, only the abs goes away
+        let x86 = X86_64::default();
+        let abs = x86.parse_instruction(&tokenize("mov eax, [abs msg]")).unwrap().encode().unwrap();
+        assert_eq!(abs.get_bytes(), &vec![0x8B, 0x04, 0x25, 0, 0, 0, 0]);
+        assert!(RiscV64.set_default_rel(true).is_err());
+    }
+
+    #[test]
+    fn test_x86_64_specifiers() {
+        let x86 = X86_64::default();
+        let encode = |line: &str| x86.parse_instruction(&tokenize(line)).unwrap().encode().unwrap();
+
+        let call = encode("call puts@PLT");
+        assert_eq!(call.get_refs()[0].to, "puts");
+        assert_eq!(call.get_refs()[0].specifier, Some(Specifier::Plt));
+        let load = encode("mov rax, [rip + x@gotpcrel]");
+        assert_eq!(load.get_bytes(), &vec![0x48, 0x8B, 0x05, 0, 0, 0, 0]);
+        assert_eq!(load.get_refs()[0].specifier, Some(Specifier::GotPcRel));
+        assert_eq!(encode("mov eax, dword ptr fs:[x@TPOFF]").get_refs()[0].specifier, Some(Specifier::TpOff));
+        assert_eq!(encode("call \"a@PLT\"").get_refs()[0].to, "a@PLT");
+        assert!(matches!(x86.parse_instruction(&tokenize("call puts@FOO")), Err((0, ParseError::InvalidOperand))));
+
+        let mut att = X86_64::default();
+        att.set_syntax(Syntax::Att).unwrap();
+        let load = att.parse_instruction(&tokenize("movq x@GOTPCREL(%rip), %rax")).unwrap().encode().unwrap();
+        assert_eq!(load.get_refs()[0].specifier, Some(Specifier::GotPcRel));
+
+        let kind = |line: &str, format| x86.relocation(&encode(line).get_refs()[0], true, format).map(|r| r.kind);
+        assert_eq!(kind("call puts@PLT", ObjectFileType::Elf), Ok(RelocationKind::PltRelative));
+        assert_eq!(kind("mov rax, [rip + x@GOTPCREL]", ObjectFileType::MachO), Ok(RelocationKind::GotRelative));
+        let gottpoff = kind("mov rax, [rip + x@GOTTPOFF]", ObjectFileType::Elf);
+        assert_eq!(gottpoff, Ok(RelocationKind::Elf(elf::R_X86_64_GOTTPOFF)));
+        assert!(kind("mov rax, [rip + x@TLVP]", ObjectFileType::Elf).is_err());
+        assert!(kind("mov rax, [rip + x@TLSGD]", ObjectFileType::MachO).is_err());
+    }
+
+    #[test]
+    fn test_x86_64_addends() {
+        let x86 = X86_64::default();
+        let encode = |line: &str| x86.parse_instruction(&tokenize(line)).unwrap().encode().unwrap();
+        let relocation = |line: &str, format| {
+            let r = x86.relocation(&encode(line).get_refs()[0], true, format).unwrap();
+            (r.size, r.addend)
+        };
+
+        let store = encode("mov dword ptr [rip + x], 5");
+        assert_eq!((store.get_refs()[0].size, store.get_refs()[0].to_end), (32, 8));
+        assert_eq!(relocation("mov dword ptr [rip + x], 5", ObjectFileType::Elf), (32, -8));
+        assert_eq!(relocation("mov byte ptr [rip + x], 5", ObjectFileType::Elf), (32, -5));
+        assert_eq!(relocation("call puts", ObjectFileType::Elf), (32, -4));
+        assert_eq!(relocation("mov eax, dword ptr [x]", ObjectFileType::Elf), (32, 0));
+        assert_eq!(relocation("mov rax, offset x", ObjectFileType::Elf), (32, 0));
+        assert_eq!(relocation("mov dword ptr [rip + x], 5", ObjectFileType::MachO), (32, -8));
+        assert_eq!(relocation("mov rdi, [rip + x@TLVP]", ObjectFileType::MachO), (32, 0));
+
+        // The addend of the expression is added by the assembler, only the one of the fixup is up to the target
+        assert_eq!(encode("lea rax, [rip + table + 8]").get_refs()[0].addend, 8);
+        assert_eq!(relocation("lea rax, [rip + table + 8]", ObjectFileType::Elf), (32, -4));
+    }
+
+    #[test]
+    fn test_x86_64_signed_immediates() {
+        let x86 = X86_64::default();
+        let encoding = |line: &str| {
+            let encoded = x86.parse_instruction(&tokenize(line)).unwrap().encode().unwrap();
+            x86.relocation(&encoded.get_refs()[0], false, ObjectFileType::Elf).unwrap().encoding
+        };
+
+        // Only immediates the CPU sign extends to 64 bits get R_X86_64_32S
+        assert_eq!(encoding("mov eax, offset x + 8"), RelocationEncoding::Generic);
+        assert_eq!(encoding("add eax, offset x"), RelocationEncoding::Generic);
+        assert_eq!(encoding("mov dword ptr [rax], offset x"), RelocationEncoding::Generic);
+        assert_eq!(encoding("mov rax, offset x"), RelocationEncoding::X86Signed);
+        assert_eq!(encoding("mov qword ptr [rax], offset x"), RelocationEncoding::X86Signed);
+        assert_eq!(encoding("push offset x"), RelocationEncoding::X86Signed);
+        assert_eq!(encoding("mov eax, [x]"), RelocationEncoding::X86Signed);
+    }
+
+    #[test]
+    fn test_x86_64_relaxation() {
+        let mut x86 = X86_64::default();
+        let kind = |x86: &X86_64, line: &str, preemptible| {
+            let encoded = x86.parse_instruction(&tokenize(line)).unwrap().encode().unwrap();
+            x86.relocation(&encoded.get_refs()[0], preemptible, ObjectFileType::Elf).unwrap().kind
+        };
+
+        assert_eq!(kind(&x86, "call puts", true), RelocationKind::PltRelative);
+        assert_eq!(kind(&x86, "jmp puts", true), RelocationKind::PltRelative);
+        assert_eq!(kind(&x86, "call local", false), RelocationKind::Relative);
+        assert_eq!(kind(&x86, "lea rdi, [rip + msg]", true), RelocationKind::Relative);
+
+        let rex_gotpcrelx = RelocationKind::Elf(elf::R_X86_64_REX_GOTPCRELX);
+        let gotpcrelx = RelocationKind::Elf(elf::R_X86_64_GOTPCRELX);
+        assert_eq!(kind(&x86, "mov rax, [rip + x@GOTPCREL]", true), rex_gotpcrelx);
+        assert_eq!(kind(&x86, "add r8, [rip + x@GOTPCREL]", true), rex_gotpcrelx);
+        assert_eq!(kind(&x86, "mov eax, [rip + x@GOTPCREL]", true), gotpcrelx);
+        assert_eq!(kind(&x86, "call qword ptr [rip + x@GOTPCREL]", true), gotpcrelx);
+        assert_eq!(kind(&x86, "lea rax, [rip + x@GOTPCREL]", true), RelocationKind::GotRelative);
+
+        x86.set_relax_relocations(false).unwrap();
+        assert_eq!(kind(&x86, "mov rax, [rip + x@GOTPCREL]", true), RelocationKind::GotRelative);
+        assert_eq!(kind(&x86, "call puts", true), RelocationKind::PltRelative);
+    }
+
+    #[test]
+    fn test_x86_64_differences() {
+        let difference = |minus, size| Reference {
+            to: "a".to_string(),
+            minus: Some(minus),
+            addend: 0,
+            at: 0,
+            rel: Relativity::Absolute,
+            specifier: None,
+            size,
+            signed: false,
+            to_end: 0,
+            relax: None,
+        };
+        let relocation = |minus, size, format| X86_64::default().relocation(&difference(minus, size), true, format);
+        let symbol = || Subtrahend::Symbol("b".to_string());
+
+        let pc32 = relocation(Subtrahend::Location, 32, ObjectFileType::Elf).unwrap();
+        assert_eq!((pc32.kind, pc32.addend, pc32.subtractor), (RelocationKind::Relative, 0, None));
+        let pc64 = relocation(symbol(), 64, ObjectFileType::Elf).unwrap();
+        assert_eq!(pc64.kind, RelocationKind::Elf(elf::R_X86_64_PC64));
+
+        let pair = relocation(symbol(), 32, ObjectFileType::MachO).unwrap();
+        assert_eq!(pair.kind, RelocationKind::MachO { value: macho::X86_64_RELOC_UNSIGNED, relative: false });
+        let subtractor = RelocationKind::MachO { value: macho::X86_64_RELOC_SUBTRACTOR, relative: false };
+        assert_eq!(pair.subtractor, Some(subtractor));
+        assert!(relocation(Subtrahend::Location, 64, ObjectFileType::MachO).is_err());
+    }
+
+    #[test]
+    fn test_x86_64_data_relocations() {
+        let data = |size| Reference {
+            to: "a".to_string(),
+            minus: None,
+            addend: 0,
+            at: 0,
+            rel: Relativity::Absolute,
+            specifier: None,
+            size,
+            signed: false,
+            to_end: 0,
+            relax: None,
+        };
+        let relocation = |size| X86_64::default().relocation(&data(size), false, ObjectFileType::Elf).unwrap();
+
+        // Unlike in instructions, 32-bit data isn't sign extended
+        for size in [8, 16, 32, 64] {
+            let typ = relocation(size);
+            assert_eq!((typ.kind, typ.encoding, typ.size), (RelocationKind::Absolute, RelocationEncoding::Generic, size));
+        }
+    }
+
+    #[test]
+    fn test_x86_64_specifier_sizes() {
+        let data = |specifier, size| Reference {
+            to: "a".to_string(),
+            minus: None,
+            addend: 0,
+            at: 0,
+            rel: Relativity::Absolute,
+            specifier: Some(specifier),
+            size,
+            signed: false,
+            to_end: 0,
+            relax: None,
+        };
+        let kind = |specifier, size| {
+            X86_64::default().relocation(&data(specifier, size), false, ObjectFileType::Elf).map(|r| r.kind)
+        };
+
+        assert_eq!(kind(Specifier::DtpOff, 64), Ok(RelocationKind::Elf(elf::R_X86_64_DTPOFF64)));
+        assert_eq!(kind(Specifier::DtpOff, 32), Ok(RelocationKind::Elf(elf::R_X86_64_DTPOFF32)));
+        assert_eq!(kind(Specifier::TpOff, 64), Ok(RelocationKind::Elf(elf::R_X86_64_TPOFF64)));
+        assert_eq!(kind(Specifier::Got, 64), Ok(RelocationKind::Elf(elf::R_X86_64_GOT64)));
+        assert_eq!(kind(Specifier::GotPcRel, 64), Ok(RelocationKind::Elf(elf::R_X86_64_GOTPCREL64)));
+        assert!(kind(Specifier::GotTpOff, 64).is_err());
+        assert!(kind(Specifier::Plt, 64).is_err());
+        assert!(kind(Specifier::TpOff, 16).is_err());
+
+        let mut difference = data(Specifier::DtpOff, 64);
+        difference.minus = Some(Subtrahend::Symbol("b".to_string()));
+        assert!(X86_64::default().relocation(&difference, false, ObjectFileType::Elf).is_err());
+    }
+
+    #[test]
+    fn test_x86_64_nops() {
+        let target = X86_64::default();
+
+        assert_eq!(target.nops(1, None).unwrap(), vec![0x90]);
+        assert_eq!(target.nops(12, None).unwrap(), vec![0x66, 0x66, 0x2E, 0x0F, 0x1F, 0x84, 0, 0, 0, 0, 0, 0x90]);
+        assert_eq!(target.nops(5, Some(2)).unwrap(), vec![0x66, 0x90, 0x66, 0x90, 0x90]);
+        assert!(target.nops(5, Some(12)).is_none());
+
+        // Long padding is jumped over
+        let nops = target.nops(88, None).unwrap();
+        assert_eq!((nops.len(), &nops[..2]), (88, &[0xEB, 86][..]));
+        let nops = target.nops(200, None).unwrap();
+        assert_eq!((nops.len(), &nops[..5]), (200, &[0xE9, 195, 0, 0, 0][..]));
+        // The jump is no longer than the other NOPs, a single byte isn't enough for one
+        assert_eq!(target.nops(20, Some(1)).unwrap(), vec![0x90; 20]);
+        let nops = target.nops(20, Some(2)).unwrap();
+        assert_eq!((nops.len(), &nops[..4]), (20, &[0xEB, 18, 0x66, 0x90][..]));
+        assert!(target.nops(200, Some(4)).unwrap().chunks(4).all(|nop| nop == [0x0F, 0x1F, 0x40, 0x00]));
+
+        let nops = RiscV64.nops(6, None).unwrap();
+        assert_eq!(nops, vec![0, 0, 0x13, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_instruction_table() {
+        let csv = r#""Instruction","Opcode","Valid 64-bit","Valid 32-bit","Valid 16-bit","Feature Flags","Operand 1","Operand 2","Operand 3","Operand 4","Tuple Type","Description"
+"CLAC","0F 01 CA","Valid","Valid","Invalid","SMAP","","","","","","Clear the AC flag in the EFLAGS register."
+"AAA","37","Invalid","Valid","Valid","","","","","","","ASCII adjust AL after addition."
+"UD1 r32, r/m32","0F B9 /r","Valid","Valid","Valid","","ModRM:reg (r)","ModRM:r/m (r)","","","","Raise invalid opcode exception."
+"ANDN r32a, r32b, r/m32","VEX.LZ.0F38.W0 F2 /r","Valid","Valid","Invalid","BMI1","ModRM:reg (w)","VEX.vvvv (r)","ModRM:r/m (r)","","","Bitwise AND of inverted r32b with r/m32, store result in r32a.""#;
+
+        let mut target = X86_64::default();
+        assert!(target.parse_instruction(&tokenize("clac")).is_err());
+        // AAA doesn't exist in 64-bit mode
+        assert_eq!(target.load_instruction_table(csv), Ok(2));
+        assert!(target.parse_instruction(&tokenize("aaa")).is_err());
+
+        let clac = target.parse_instruction(&tokenize("clac")).unwrap();
+        assert_eq!(clac.encode().unwrap().get_bytes(), &vec![0x0F, 0x01, 0xCA]);
+        let ud1 = target.parse_instruction(&tokenize("ud1 eax, ecx")).unwrap();
+        assert_eq!(ud1.encode().unwrap().get_bytes(), &vec![0x0F, 0xB9, 0xC1]);
+
+        // Built in instructions still work
+        let push = target.parse_instruction(&tokenize("push rbp")).unwrap();
+        assert_eq!(push.encode().unwrap().get_bytes(), &vec![0x55u8]);
+
+        let forms = target.instruction_forms(Some("clac"));
+        assert_eq!(forms.len(), 1);
+        assert_eq!(forms[0].0, "clac");
+        assert!(forms[0].1.ends_with("Clear the AC flag in the EFLAGS register. (SMAP)"));
+
+        let bad = "header\n\"FOO xmm9\",\"0F 00\"";
+        assert!(X86_64::default().load_instruction_table(bad).is_err());
+    }
+
+    #[test]
+    fn test_instruction_table_opcodes() {
+        let csv = r#"header
+"MYSX r64, r/m8","REX.w 0F BE /r"
+"MYSXP r64, r/m8","REX.w + 0F BE /r"
+"MYENTER imm16, 1","C8 iw 01""#;
+
+        // Like the generator, REX.W is read in any case and forms with a byte after an immediate are skipped
+        let mut target = X86_64::default();
+        assert_eq!(target.load_instruction_table(csv), Ok(2));
+
+        let mysx = target.parse_instruction(&tokenize("mysx rax, byte ptr [rdi]")).unwrap();
+        assert_eq!(mysx.encode().unwrap().get_bytes(), &vec![0x48, 0x0F, 0xBE, 0x07]);
+        let mysxp = target.parse_instruction(&tokenize("mysxp rax, byte ptr [rdi]")).unwrap();
+        assert_eq!(mysxp.encode().unwrap().get_bytes(), &vec![0x48, 0x0F, 0xBE, 0x07]);
+        assert!(target.parse_instruction(&tokenize("myenter 16, 1")).is_err());
+    }
+}
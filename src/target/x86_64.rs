@@ -0,0 +1,397 @@
+// aron (c) Nikolas Wipper 2022
+
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use crate::assembler::ObjectFileType;
+use crate::instructions::{Encodable, Reference, Register, Relax, Subtrahend};
+use crate::parse::att::{self, Translation};
+use crate::parse::encodings::TEMPLATES;
+use crate::parse::templates::{lookup, matches};
+use crate::parse::features::FeatureSet;
+use crate::parse::helpers::{is_register_name, parse_integer, Relativity, Specifier};
+use crate::parse::insn::parse_insn;
+use crate::parse::templates::{matches_templates, parse_template_csv, InstructionTemplate};
+use crate::parse::lexer::{Token, OPERATORS};
+use crate::parse::{ParseError, Syntax};
+use crate::target::{RelocationType, Target};
+use object::{elf, macho, Architecture, RelocationEncoding, RelocationKind};
+use std::str::FromStr;
+
+pub struct X86_64 {
+    /// Templates loaded at runtime through `--instr-table`
+    templates: Vec<InstructionTemplate>,
+    features: FeatureSet,
+    syntax: Syntax,
+    /// Whether Intel syntax registers are written with a `%` in front, after `.intel_syntax prefix`
+    register_prefix: bool,
+    /// Whether memory operands that are only a symbol are relative to rip, after `--default-rel` or `default rel`
+    default_rel: bool,
+    /// Whether GOT loads use the relocations the linker may relax, turned off by `-mrelax-relocations=no`
+    relax_relocations: bool,
+}
+
+/// The recommended NOPs of each length up to 11 bytes, the same GNU as pads x86-64 code with
+const NOPS: [&[u8]; 11] = [
+    &[0x90],
+    &[0x66, 0x90],
+    &[0x0F, 0x1F, 0x00],
+    &[0x0F, 0x1F, 0x40, 0x00],
+    &[0x0F, 0x1F, 0x44, 0x00, 0x00],
+    &[0x66, 0x0F, 0x1F, 0x44, 0x00, 0x00],
+    &[0x0F, 0x1F, 0x80, 0x00, 0x00, 0x00, 0x00],
+    &[0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[0x66, 0x2E, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[0x66, 0x66, 0x2E, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+];
+
+/// Relocates differences like `.long .LBB0_3-.LJTI0_0` or `.quad sym - .`. ELF makes them pc-relative and the
+/// writer adds the distance between the value and the subtracted symbol. Mach-O pairs a relocation for each symbol
+fn difference(reference: &Reference, minus: &Subtrahend, format: ObjectFileType) -> Result<RelocationType, String> {
+    let relocation = |kind, subtractor| RelocationType {
+        kind,
+        encoding: RelocationEncoding::Generic,
+        size: reference.size,
+        addend: 0,
+        subtractor,
+    };
+
+    // Like in GNU as, `foo@GOT - bar` has no relocation
+    if let Some(specifier) = reference.specifier {
+        return Err(format!("Can't relocate the difference of a symbol with {:?}", specifier));
+    }
+
+    match (format, minus, reference.size) {
+        (ObjectFileType::Elf, _, 64) => Ok(relocation(RelocationKind::Elf(elf::R_X86_64_PC64), None)),
+        (ObjectFileType::Elf, _, _) => Ok(relocation(RelocationKind::Relative, None)),
+        (ObjectFileType::MachO, Subtrahend::Location, 32) => Ok(relocation(RelocationKind::Relative, None)),
+        (ObjectFileType::MachO, Subtrahend::Symbol(_), 32 | 64) => {
+            let unsigned = RelocationKind::MachO { value: macho::X86_64_RELOC_UNSIGNED, relative: false };
+            let subtractor = RelocationKind::MachO { value: macho::X86_64_RELOC_SUBTRACTOR, relative: false };
+            Ok(relocation(unsigned, Some(subtractor)))
+        }
+        _ => Err(format!("Differences of {} bits can't be relocated in {:?} files", reference.size, format)),
+    }
+}
+
+impl Default for X86_64 {
+    fn default() -> Self {
+        X86_64 {
+            templates: Vec::new(),
+            features: FeatureSet::default(),
+            syntax: Syntax::default(),
+            register_prefix: false,
+            default_rel: false,
+            // GNU as relaxes them by default too
+            relax_relocations: true,
+        }
+    }
+}
+
+/// Turns Intel syntax with `%` in front of registers into the plain one, token for token. Bare register names are
+/// symbols there, so they become quoted tokens, which the parser doesn't take for registers
+fn strip_register_prefixes(tokens: &[Token]) -> Result<Vec<Token>, (usize, ParseError)> {
+    let mut res = vec![tokens[0].clone()];
+    for (i, token) in tokens.iter().enumerate().skip(1) {
+        res.push(if let Some(name) = token.as_str().strip_prefix('%') {
+            if !is_register_name(name) {
+                return Err((tokens.len() - i - 1, ParseError::InvalidOperand));
+            }
+            Token::new(name)
+        } else if is_register_name(token.as_str()) {
+            Token::quoted(token.as_str())
+        } else {
+            token.clone()
+        });
+    }
+
+    Ok(res)
+}
+
+/// Drops the `abs` from `[abs symbol]`, which always stays absolute, and makes other memory operands that are only a
+/// symbol relative to rip in default rel mode. Segment overrides, like `fs:[x@tpoff]`, stay absolute as well
+fn address_mode(translation: Translation, default_rel: bool) -> Translation {
+    let mut res = Translation::with_capacity(translation.len() + 2);
+    let mut i = 0;
+
+    while i < translation.len() {
+        let (token, at) = translation[i].clone();
+        res.push((token.clone(), at));
+        i += 1;
+        if token != "[" || token.is_quoted() {
+            continue;
+        }
+
+        let close = translation[i..].iter().position(|t| t.0 == "]").map(|p| i + p).unwrap_or(translation.len());
+        let inner = &translation[i..close];
+        if inner.len() > 1 && !inner[0].0.is_quoted() && inner[0].0.as_str().eq_ignore_ascii_case("abs") {
+            i += 1;
+            continue;
+        }
+
+        let segment = res.len() >= 3 && res[res.len() - 2].0 == ":";
+        let register = inner.iter().any(|t| !t.0.is_quoted() && is_register_name(t.0.as_str()));
+        let symbol = inner.iter().any(|t| {
+            t.0.is_quoted()
+                || (parse_integer(t.0.as_str()).is_none()
+                    && !OPERATORS.contains(&t.0.as_str())
+                    && !is_register_name(t.0.as_str()))
+        });
+        if default_rel && !segment && !register && symbol {
+            res.push((Token::new("rip"), at));
+            res.push((Token::new("+"), at));
+        }
+    }
+
+    res
+}
+
+impl X86_64 {
+    fn parse_intel(&self, tokens: &[Token]) -> Result<Box<dyn Encodable>, (usize, ParseError)> {
+        if self.templates.is_empty() {
+            return Ok(Box::new(matches(tokens, &self.features)?));
+        }
+
+        let builtin = matches(tokens, &self.features);
+        let instr = match (builtin, matches_templates(&self.templates, tokens, &self.features)) {
+            (Ok(builtin), Ok(extra)) => {
+                if extra.encoded_len() < builtin.encoded_len() {
+                    extra
+                } else {
+                    builtin
+                }
+            }
+            (Ok(instr), Err(_)) | (Err(_), Ok(instr)) => instr,
+            (Err(builtin), Err(extra)) => {
+                let disabled = matches!(extra.1, ParseError::DisabledFeature(_));
+                return Err(if extra.0 < builtin.0 || disabled { extra } else { builtin });
+            }
+        };
+
+        Ok(Box::new(instr))
+    }
+}
+
+impl Target for X86_64 {
+    fn name(&self) -> &'static str {
+        "x86_64"
+    }
+
+    fn architecture(&self) -> Architecture {
+        Architecture::X86_64
+    }
+
+    fn is_register(&self, name: &str) -> bool {
+        Register::from_str(name).is_ok()
+    }
+
+    fn parse_instruction(&self, tokens: &[Token]) -> Result<Box<dyn Encodable>, (usize, ParseError)> {
+        // The NASM front end hands over instructions already in Intel syntax
+        let translation = match self.syntax {
+            Syntax::Att => {
+                let known = |mnemonic: &str| {
+                    !lookup(mnemonic).is_empty() || self.templates.iter().any(|t| t.mnemonic == mnemonic)
+                };
+                att::translate(tokens, &known)?
+            }
+            _ if self.register_prefix => strip_register_prefixes(tokens)?.into_iter().zip(0..).collect(),
+            _ => tokens.iter().cloned().zip(0..).collect(),
+        };
+        let translation = address_mode(translation, self.default_rel);
+        let intel = translation.iter().map(|t| t.0.clone()).collect::<Vec<Token>>();
+
+        // Point errors at the token the offending Intel token was made from
+        self.parse_intel(&intel).map_err(|(i, e)| {
+            let at = translation.get(intel.len().saturating_sub(i + 1)).map(|t| t.1).unwrap_or(tokens.len() - 1);
+            (tokens.len() - at - 1, e)
+        })
+    }
+
+    fn parse_raw_instruction(&self, tokens: &[Token]) -> Result<Box<dyn Encodable>, (usize, ParseError)> {
+        Ok(Box::new(parse_insn(tokens)?))
+    }
+
+    fn set_arch(&mut self, arch: &str) -> Result<(), String> {
+        self.features.apply(arch)
+    }
+
+    fn set_syntax(&mut self, syntax: Syntax) -> Result<(), String> {
+        self.syntax = syntax;
+        Ok(())
+    }
+
+    fn set_default_rel(&mut self, default_rel: bool) -> Result<(), String> {
+        self.default_rel = default_rel;
+        Ok(())
+    }
+
+    fn set_relax_relocations(&mut self, relax: bool) -> Result<(), String> {
+        self.relax_relocations = relax;
+        Ok(())
+    }
+
+    fn nops(&self, count: usize, limit: Option<usize>) -> Option<Vec<u8>> {
+        let limit = limit.unwrap_or(NOPS.len());
+        if limit > NOPS.len() {
+            return None;
+        }
+
+        // Like GNU as, long padding is jumped over instead of executing many NOPs. The jump stands in for one of them,
+        // so it can't be longer than the limit either
+        let mut bytes = Vec::with_capacity(count);
+        let mut count = count;
+        if count / limit > 7 {
+            if limit >= 2 && count - 2 <= i8::MAX as usize {
+                bytes.extend_from_slice(&[0xEB, (count - 2) as u8]);
+                count -= 2;
+            } else if limit >= 5 {
+                bytes.push(0xE9);
+                bytes.extend_from_slice(&((count - 5) as u32).to_le_bytes());
+                count -= 5;
+            }
+        }
+
+        for _ in 0..count / limit {
+            bytes.extend_from_slice(NOPS[limit - 1]);
+        }
+        if !count.is_multiple_of(limit) {
+            bytes.extend_from_slice(NOPS[count % limit - 1]);
+        }
+        Some(bytes)
+    }
+
+    fn align_in_bytes(&self, format: ObjectFileType) -> bool {
+        // Only GNU as for ELF does that, Apple's as doesn't
+        format == ObjectFileType::Elf
+    }
+
+    fn set_register_prefix(&mut self, prefix: bool) -> Result<(), String> {
+        // Todo: AT&T syntax without prefixes
+        if self.syntax == Syntax::Att && !prefix {
+            return Err("AT&T syntax without register prefixes isn't supported".to_string());
+        }
+        self.register_prefix = prefix;
+        Ok(())
+    }
+
+    fn load_instruction_table(&mut self, csv: &str) -> Result<usize, String> {
+        let templates = parse_template_csv(csv)?;
+        let count = templates.len();
+        self.templates.extend(templates);
+        Ok(count)
+    }
+
+    fn instruction_forms(&self, mnemonic: Option<&str>) -> Vec<(String, String)> {
+        let builtin = match mnemonic {
+            Some(mnemonic) => lookup(mnemonic),
+            None => &TEMPLATES,
+        };
+        let extra = self.templates.iter().filter(|t| mnemonic.map(|m| m == t.mnemonic).unwrap_or(true));
+
+        builtin
+            .iter()
+            .chain(extra)
+            .map(|t| {
+                let feature = t.feature.map(|f| format!(" ({})", f)).unwrap_or_default();
+                (t.to_string(), format!("{:<28}{}{}", t.encoding(), t.description, feature))
+            })
+            .collect()
+    }
+
+    fn relocation(
+        &self,
+        reference: &Reference,
+        preemptible: bool,
+        format: ObjectFileType,
+    ) -> Result<RelocationType, String> {
+        if let Some(minus) = &reference.minus {
+            return difference(reference, minus, format);
+        }
+
+        let kind = match reference.rel {
+            Relativity::Absolute => RelocationKind::Absolute,
+            Relativity::Relative | Relativity::RipRelative => RelocationKind::Relative,
+            _ => return Err(format!("Relocation {:?} is not supported on x86_64", reference.rel)),
+        };
+
+        // Mach-O only has unsigned absolute relocations
+        let is_elf = format == ObjectFileType::Elf;
+        let encoding = match reference.rel {
+            Relativity::Absolute if reference.size == 32 && reference.signed && is_elf => RelocationEncoding::X86Signed,
+            Relativity::Absolute => RelocationEncoding::Generic,
+            Relativity::Relative => RelocationEncoding::X86Branch,
+            _ => RelocationEncoding::X86RipRelative,
+        };
+
+        // Specifiers pick the relocation themselves, ELF has a type for each one, Mach-O only knows a few
+        // Only the absolute specifiers and GOTPCREL in ELF have 64 bit forms, everything else needs 32 bits
+        if let Some(specifier) = reference.specifier {
+            let absolute = matches!(specifier, Specifier::Got | Specifier::TpOff | Specifier::DtpOff);
+            let wide = absolute || (specifier == Specifier::GotPcRel && is_elf);
+            if reference.size != 32 && !(reference.size == 64 && wide) {
+                return Err(format!("{:?} can't be relocated in {} bits", specifier, reference.size));
+            }
+            if absolute && reference.rel != Relativity::Absolute {
+                return Err(format!("{:?} can't be relocated relative to rip", specifier));
+            }
+        }
+
+        let (kind, encoding) = match (reference.specifier, format) {
+            // Branches to symbols from other modules go through the PLT in position independent executables
+            (None, ObjectFileType::Elf) if reference.rel == Relativity::Relative && preemptible => {
+                (RelocationKind::PltRelative, RelocationEncoding::X86Branch)
+            }
+            (None, _) => (kind, encoding),
+            (Some(Specifier::Plt), _) => (RelocationKind::PltRelative, RelocationEncoding::X86Branch),
+            (Some(Specifier::GotPcRel), ObjectFileType::Elf) if reference.size == 64 => {
+                (RelocationKind::Elf(elf::R_X86_64_GOTPCREL64), RelocationEncoding::Generic)
+            }
+            // The linker can turn relaxable GOT loads into direct accesses if the symbol is local after all
+            (Some(Specifier::GotPcRel), ObjectFileType::Elf) if self.relax_relocations => match reference.relax {
+                Some(Relax::Plain) => (RelocationKind::Elf(elf::R_X86_64_GOTPCRELX), RelocationEncoding::Generic),
+                Some(Relax::Rex) => (RelocationKind::Elf(elf::R_X86_64_REX_GOTPCRELX), RelocationEncoding::Generic),
+                None => (RelocationKind::GotRelative, RelocationEncoding::Generic),
+            },
+            (Some(Specifier::GotPcRel), _) => (RelocationKind::GotRelative, RelocationEncoding::Generic),
+            (Some(specifier), ObjectFileType::Elf) if specifier != Specifier::Tlvp => {
+                let wide = reference.size == 64;
+                let r_type = match specifier {
+                    Specifier::Got if wide => elf::R_X86_64_GOT64,
+                    Specifier::Got => elf::R_X86_64_GOT32,
+                    Specifier::TpOff if wide => elf::R_X86_64_TPOFF64,
+                    Specifier::TpOff => elf::R_X86_64_TPOFF32,
+                    Specifier::GotTpOff => elf::R_X86_64_GOTTPOFF,
+                    Specifier::TlsGd => elf::R_X86_64_TLSGD,
+                    Specifier::TlsLd => elf::R_X86_64_TLSLD,
+                    _ if wide => elf::R_X86_64_DTPOFF64,
+                    _ => elf::R_X86_64_DTPOFF32,
+                };
+                (RelocationKind::Elf(r_type), RelocationEncoding::Generic)
+            }
+            (Some(Specifier::Tlvp), ObjectFileType::MachO) => {
+                (RelocationKind::MachO { value: macho::X86_64_RELOC_TLV, relative: true }, RelocationEncoding::Generic)
+            }
+            (Some(specifier), _) => {
+                return Err(format!("Relocation specifier {:?} is not supported in {:?} files", specifier, format))
+            }
+        };
+
+        // Relocations are relative to the start of the fixup, while the CPU counts from the next instruction
+        let pc_relative = matches!(reference.rel, Relativity::Relative | Relativity::RipRelative);
+        let addend = if pc_relative { -(reference.to_end as i64) } else { 0 };
+
+        Ok(RelocationType {
+            kind,
+            encoding,
+            size: reference.size,
+            addend: match kind {
+                // Mach-O counts from the end of the fixup. object makes up for that itself, except for raw relocations
+                RelocationKind::MachO { .. } if pc_relative => addend + reference.size as i64 / 8,
+                _ => addend,
+            },
+            subtractor: None,
+        })
+    }
+}